@@ -0,0 +1,165 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Closest point of approach of two vessels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cpa {
+    /// Time to the closest point of approach in seconds (TCPA). Negative value means that the
+    /// vessels are diverging and the closest point was passed that many seconds ago.
+    pub time_seconds: f64,
+
+    /// Distance between the vessels at the closest point of approach in nautical miles (DCPA)
+    pub distance_nm: f64,
+}
+
+/// Compute the closest point of approach of two vessels from their positions and SOG/COG
+/// vectors. Returns `None` if position, SOG or COG of either vessel is not available.
+///
+/// The vessels are assumed to keep their course and speed. Positions are projected on a local
+/// flat-earth plane around the first vessel, so the result is reasonable only for vessels
+/// close to each other and away from the poles. If the vessels have identical velocities the
+/// distance never changes and the current distance is returned with zero time.
+pub fn cpa(a: &VesselDynamicData, b: &VesselDynamicData) -> Option<Cpa> {
+    // Relative position of the second vessel in nautical miles
+    let (rx, ry) = {
+        let mean_latitude = Float::to_radians((a.latitude? + b.latitude?) / 2.0);
+        let mut dlon = b.longitude? - a.longitude?;
+        if dlon > 180.0 {
+            dlon -= 360.0;
+        } else if dlon < -180.0 {
+            dlon += 360.0;
+        }
+        // One minute of latitude equals one nautical mile
+        (
+            dlon * 60.0 * Float::cos(mean_latitude),
+            (b.latitude? - a.latitude?) * 60.0,
+        )
+    };
+    let (avx, avy) = velocity(a)?;
+    let (bvx, bvy) = velocity(b)?;
+
+    // Relative velocity of the second vessel in knots
+    let (vx, vy) = (bvx - avx, bvy - avy);
+
+    let speed2 = vx * vx + vy * vy;
+    let time_hours = if speed2 > f64::EPSILON {
+        -(rx * vx + ry * vy) / speed2
+    } else {
+        0.0
+    };
+    let (dx, dy) = (rx + vx * time_hours, ry + vy * time_hours);
+    Some(Cpa {
+        time_seconds: time_hours * 3600.0,
+        distance_nm: Float::sqrt(dx * dx + dy * dy),
+    })
+}
+
+/// Return the east and north components of the velocity of the vessel in knots.
+fn velocity(vdd: &VesselDynamicData) -> Option<(f64, f64)> {
+    let sog = vdd.sog_knots?;
+    let course = Float::to_radians(vdd.cog?);
+    Some((sog * Float::sin(course), sog * Float::cos(course)))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cpa_head_on() {
+        // Two vessels 10 nm apart on the same meridian heading towards each other at 10 knots
+        let a = VesselDynamicData {
+            latitude: Some(60.0),
+            longitude: Some(25.0),
+            sog_knots: Some(10.0),
+            cog: Some(0.0),
+            ..Default::default()
+        };
+        let b = VesselDynamicData {
+            latitude: Some(60.0 + 10.0 / 60.0),
+            longitude: Some(25.0),
+            sog_knots: Some(10.0),
+            cog: Some(180.0),
+            ..Default::default()
+        };
+        let c = cpa(&a, &b).unwrap();
+        assert::close(c.time_seconds, 1800.0, 0.001);
+        assert::close(c.distance_nm, 0.0, 0.000001);
+
+        // Moving away from each other, the closest point was passed half an hour ago
+        let a = VesselDynamicData {
+            latitude: Some(60.0),
+            longitude: Some(25.0),
+            sog_knots: Some(10.0),
+            cog: Some(180.0),
+            ..Default::default()
+        };
+        let b = VesselDynamicData {
+            latitude: Some(60.0 + 10.0 / 60.0),
+            longitude: Some(25.0),
+            sog_knots: Some(10.0),
+            cog: Some(0.0),
+            ..Default::default()
+        };
+        let diverging = cpa(&a, &b).unwrap();
+        assert::close(diverging.time_seconds, -1800.0, 0.001);
+        assert::close(diverging.distance_nm, 0.0, 0.000001);
+    }
+
+    #[test]
+    fn test_cpa_parallel() {
+        // Two vessels on parallel tracks 1 nm apart with identical speed
+        let a = VesselDynamicData {
+            latitude: Some(60.0),
+            longitude: Some(25.0),
+            sog_knots: Some(12.0),
+            cog: Some(0.0),
+            ..Default::default()
+        };
+        let b = VesselDynamicData {
+            latitude: Some(60.0),
+            longitude: Some(25.0 + 2.0 / 60.0),
+            sog_knots: Some(12.0),
+            cog: Some(0.0),
+            ..Default::default()
+        };
+        let c = cpa(&a, &b).unwrap();
+        assert::close(c.time_seconds, 0.0, 0.001);
+        assert::close(c.distance_nm, 1.0, 0.001);
+
+        // Overtaking on a parallel track: closest when abeam
+        let b = VesselDynamicData {
+            latitude: Some(60.0 - 1.0 / 60.0),
+            longitude: Some(25.0 + 2.0 / 60.0),
+            sog_knots: Some(14.0),
+            cog: Some(0.0),
+            ..Default::default()
+        };
+        let c = cpa(&a, &b).unwrap();
+        assert::close(c.time_seconds, 1800.0, 0.001);
+        assert::close(c.distance_nm, 1.0, 0.001);
+
+        let mut b = b;
+        b.cog = None;
+        assert_eq!(cpa(&a, &b), None);
+    }
+}
@@ -40,30 +40,41 @@ pub(crate) mod vdm_t24;
 pub(crate) mod vdm_t25;
 pub(crate) mod vdm_t26;
 pub(crate) mod vdm_t27;
+pub(crate) mod track_smoother;
+pub(crate) mod cpa;
+pub(crate) mod rate_limiter;
 
 use super::*;
+use num_traits::Float;
 pub use vdm_t4::BaseStationReport;
-pub use vdm_t6::BinaryAddressedMessage;
-pub use vdm_t8::BinaryBroadcastMessage;
+pub use vdm_t6::{BinaryAddressedMessage, Type6Payload};
+pub use vdm_t8::vdm_t8_payloads::{ExtendedShipStaticData, MeteoHydroData11, MeteoHydroData31};
+pub use vdm_t8::{BinaryBroadcastMessage, Type8Payload};
 pub use vdm_t9::StandardSarAircraftPositionReport;
 pub use vdm_t10::UtcDateInquiry;
 pub use vdm_t12::AddressedSafetyRelatedMessage;
 pub use vdm_t13::SafetyRelatedAcknowledgement;
 pub use vdm_t14::SafetyRelatedBroadcastMessage;
-pub use vdm_t15::{Interrogation, InterrogationCase};
+pub use vdm_t15::{Interrogation, InterrogationCase, InterrogationRequest};
 pub use vdm_t16::AssignmentModeCommand;
 pub use vdm_t17::DgnssBroadcastBinaryMessage;
 pub use vdm_t20::{DataLinkManagementMessage};
 pub use vdm_t21::{AidToNavigationReport, NavAidType};
 pub use vdm_t22::{ChannelManagement};
-pub use vdm_t23::{GroupAssignmentCommand};
+pub use vdm_t23::{
+    reporting_interval_seconds, GroupAssignmentCommand, StationInterval, StationType,
+};
 pub use vdm_t25::{SingleSlotBinaryMessage};
 pub use vdm_t26::{MultipleSlotBinaryMessage};
+pub use track_smoother::TrackSmoother;
+pub use cpa::{cpa, Cpa};
+pub use rate_limiter::RateLimiter;
 
 // -------------------------------------------------------------------------------------------------
 
 /// AIS station based on talker id
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Station {
     BaseStation,             // !AB
     DependentAisBaseStation, // !AD
@@ -125,6 +136,7 @@ impl core::str::FromStr for Station {
 
 /// Types 1, 2, 3 and 18: Position Report Class A, and Long Range AIS Broadcast message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VesselDynamicData {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -135,6 +147,12 @@ pub struct VesselDynamicData {
     /// Class A or Class B
     pub ais_type: AisClass,
 
+    /// Message type the data was decoded from (1, 2, 3, 18 or 27)
+    pub message_type: u8,
+
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
@@ -142,8 +160,8 @@ pub struct VesselDynamicData {
     /// Navigation status
     pub nav_status: NavigationStatus,
 
-    /// Accurate ROT_sensor (±0..708°/min) if available.
-    pub rot: Option<f64>,
+    /// Rate of turn
+    pub rot: RateOfTurn,
 
     /// ROT direction when turn is more than 5°/30s.
     pub rot_direction: Option<RotDirection>,
@@ -224,10 +242,20 @@ pub struct VesselDynamicData {
     /// Diagnostic information for the radio system.
     /// <https://www.itu.int/dms_pubrec/itu-r/rec/m/R-REC-M.1371-1-200108-S!!PDF-E.pdf>
     pub radio_status: Option<u32>,
+
+    /// Communication state decoded from `radio_status` using the layout of the message type.
+    /// Type 18 uses the layout given by the communication state selector flag.
+    pub communication_state: Option<CommunicationState>,
+
+    /// Raw bits reserved for regional applications, which some authorities use for their own
+    /// extensions. Types 1, 2 and 3: the 3-bit spare field at bits 145-147. Type 18: the 8-bit
+    /// regional reserved field at bits 38-45. `None` for type 27.
+    pub regional_reserved: Option<u8>,
 }
 
 /// AIS class which is either Class A or Class B
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum AisClass {
     /// AIS class not known.
     Unknown,
@@ -265,28 +293,105 @@ impl LatLon for VesselDynamicData {
     }
 }
 
+impl VesselDynamicData {
+    /// Project the position forward by the given number of seconds along COG at SOG and
+    /// return the estimated `(latitude, longitude)` in degrees. Returns `None` if position,
+    /// SOG or COG is not available.
+    ///
+    /// Uses a small-distance approximation of the sphere and ignores rate of turn, so the
+    /// result is reasonable only for short horizons (minutes) and away from the poles.
+    pub fn extrapolate(&self, seconds: f64) -> Option<(f64, f64)> {
+        let latitude = self.latitude?;
+        let longitude = self.longitude?;
+        let distance_nm = self.sog_knots? * seconds / 3600.0;
+        let course = Float::to_radians(self.cog?);
+
+        // One minute of latitude equals one nautical mile
+        let dlat = distance_nm * Float::cos(course) / 60.0;
+        let mean_latitude = Float::to_radians(latitude + dlat / 2.0);
+        let dlon = distance_nm * Float::sin(course) / (60.0 * Float::cos(mean_latitude));
+
+        let mut longitude = longitude + dlon;
+        if longitude > 180.0 {
+            longitude -= 360.0;
+        } else if longitude < -180.0 {
+            longitude += 360.0;
+        }
+        Some((latitude + dlat, longitude))
+    }
+
+    /// Return true if the transmitter is synchronized to UTC directly or indirectly, false if
+    /// it's synchronized to a base station or to other stations. The sync state is the first
+    /// two bits of both SOTDMA and ITDMA communication states. Returns `None` if the message
+    /// has no communication state.
+    pub fn is_utc_synchronized(&self) -> Option<bool> {
+        self.radio_status.map(|radio_status| (radio_status >> 17) & 0b11 < 2)
+    }
+
+    /// Return a heuristic quality score of the report from 0 (worst) to 100 (best), useful for
+    /// picking the best of several reports of the same vessel. The score is the sum of:
+    /// - 20 if position accuracy is high
+    /// - 10 if RAIM is in use
+    /// - 20 if speed over ground is available
+    /// - 20 if course over ground is available
+    /// - 10 if true heading is available
+    /// - 20 if the timestamp second is a valid second (0-59)
+    pub fn quality_score(&self) -> u8 {
+        let mut score = 0;
+        if self.high_position_accuracy {
+            score += 20;
+        }
+        if self.raim_flag {
+            score += 10;
+        }
+        if self.sog_knots.is_some() {
+            score += 20;
+        }
+        if self.cog.is_some() {
+            score += 20;
+        }
+        if self.heading_true.is_some() {
+            score += 10;
+        }
+        if self.timestamp_seconds < 60 {
+            score += 20;
+        }
+        score
+    }
+}
+
 /// Navigation status for VesselDynamicData
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum NavigationStatus {
-    UnderWayUsingEngine = 0,        // 0
-    AtAnchor = 1,                   // 1
-    NotUnderCommand = 2,            // 2
-    RestrictedManoeuverability = 3, // 3
-    ConstrainedByDraught = 4,       // 4
-    Moored = 5,                     // 5
-    Aground = 6,                    // 6
-    EngagedInFishing = 7,           // 7
-    UnderWaySailing = 8,            // 8
-    Reserved9 = 9,                  // 9, may be renamed in the future
-    Reserved10 = 10,                // 10, may be renamed in the future
-    Reserved11 = 11,                // 11, may be renamed in the future
-    Reserved12 = 12,                // 12, may be renamed in the future
-    Reserved13 = 13,                // 13, may be renamed in the future
-    AisSartIsActive = 14,           // 14
-    NotDefined = 15,                // 15
+    UnderWayUsingEngine,        // 0
+    AtAnchor,                   // 1
+    NotUnderCommand,            // 2
+    RestrictedManoeuverability, // 3
+    ConstrainedByDraught,       // 4
+    Moored,                     // 5
+    Aground,                    // 6
+    EngagedInFishing,           // 7
+    UnderWaySailing,            // 8
+    Reserved9,                  // 9, may be renamed in the future
+    Reserved10,                 // 10, may be renamed in the future
+    Reserved11,                 // 11, may be renamed in the future
+    Reserved12,                 // 12, may be renamed in the future
+    Reserved13,                 // 13, may be renamed in the future
+    AisSartIsActive,            // 14
+    NotDefined,                 // 15
+    Reserved(u8),               // values not fitting in the 4-bit field
 }
 impl NavigationStatus {
     pub fn new(nav_status: u8) -> NavigationStatus {
+        match NavigationStatus::from_u8(nav_status) {
+            NavigationStatus::Reserved(_) => NavigationStatus::NotDefined,
+            status => status,
+        }
+    }
+
+    /// Map the given code to navigation status. Codes above 15 are mapped to `Reserved`.
+    pub fn from_u8(nav_status: u8) -> NavigationStatus {
         match nav_status {
             0 => NavigationStatus::UnderWayUsingEngine,
             1 => NavigationStatus::AtAnchor,
@@ -304,12 +409,53 @@ impl NavigationStatus {
             13 => NavigationStatus::Reserved13,
             14 => NavigationStatus::AisSartIsActive,
             15 => NavigationStatus::NotDefined,
-            _ => NavigationStatus::NotDefined,
+            _ => NavigationStatus::Reserved(nav_status),
         }
     }
 
     pub fn to_value(&self) -> u8 {
-        *self as u8
+        match self {
+            NavigationStatus::UnderWayUsingEngine => 0,
+            NavigationStatus::AtAnchor => 1,
+            NavigationStatus::NotUnderCommand => 2,
+            NavigationStatus::RestrictedManoeuverability => 3,
+            NavigationStatus::ConstrainedByDraught => 4,
+            NavigationStatus::Moored => 5,
+            NavigationStatus::Aground => 6,
+            NavigationStatus::EngagedInFishing => 7,
+            NavigationStatus::UnderWaySailing => 8,
+            NavigationStatus::Reserved9 => 9,
+            NavigationStatus::Reserved10 => 10,
+            NavigationStatus::Reserved11 => 11,
+            NavigationStatus::Reserved12 => 12,
+            NavigationStatus::Reserved13 => 13,
+            NavigationStatus::AisSartIsActive => 14,
+            NavigationStatus::NotDefined => 15,
+            NavigationStatus::Reserved(value) => *value,
+        }
+    }
+
+    /// Return human-readable description of the status.
+    pub fn description(&self) -> &'static str {
+        match self {
+            NavigationStatus::UnderWayUsingEngine => "Under way using engine",
+            NavigationStatus::AtAnchor => "At anchor",
+            NavigationStatus::NotUnderCommand => "Not under command",
+            NavigationStatus::RestrictedManoeuverability => "Restricted manoeuverability",
+            NavigationStatus::ConstrainedByDraught => "Constrained by her draught",
+            NavigationStatus::Moored => "Moored",
+            NavigationStatus::Aground => "Aground",
+            NavigationStatus::EngagedInFishing => "Engaged in fishing",
+            NavigationStatus::UnderWaySailing => "Under way sailing",
+            NavigationStatus::Reserved9 => "Reserved for high speed craft",
+            NavigationStatus::Reserved10 => "Reserved for wing in ground craft",
+            NavigationStatus::Reserved11 => "Power-driven vessel towing astern",
+            NavigationStatus::Reserved12 => "Power-driven vessel pushing ahead or towing alongside",
+            NavigationStatus::Reserved13 => "Reserved for future use",
+            NavigationStatus::AisSartIsActive => "AIS-SART, MOB-AIS or EPIRB-AIS active",
+            NavigationStatus::NotDefined => "Not defined",
+            NavigationStatus::Reserved(_) => "Reserved",
+        }
     }
 }
 
@@ -334,6 +480,7 @@ impl core::fmt::Display for NavigationStatus {
             NavigationStatus::Reserved13 => write!(f, "(reserved13)"),
             NavigationStatus::AisSartIsActive => write!(f, "ais sart is active"),
             NavigationStatus::NotDefined => write!(f, "(notDefined)"),
+            NavigationStatus::Reserved(value) => write!(f, "(reserved{})", value),
         }
     }
 }
@@ -348,6 +495,7 @@ impl Default for NavigationStatus {
 
 /// Location metadata about positioning system
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum PositioningSystemMeta {
     Operative, // When timestamp second is 0-59
     ManualInputMode,
@@ -370,6 +518,7 @@ impl core::fmt::Display for PositioningSystemMeta {
 
 /// Vessel rotation direction
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum RotDirection {
     /// Turning port (left, when seen by an observer aboard the vessel looking forward)
     Port,
@@ -399,8 +548,118 @@ impl core::fmt::Display for RotDirection {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Rate of turn of a vessel
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum RateOfTurn {
+    /// Rate of turn not available
+    #[default]
+    NotAvailable,
+
+    /// Turning right at the given rate (0..708°/min) measured by a turn indicator
+    TurningRight(f64),
+
+    /// Turning left at the given rate (0..708°/min) measured by a turn indicator
+    TurningLeft(f64),
+
+    /// Turning right at more than 5°/30s, no turn indicator available
+    HardRight,
+
+    /// Turning left at more than 5°/30s, no turn indicator available
+    HardLeft,
+}
+
+impl RateOfTurn {
+    /// Decode the signed 8-bit ROT field of AIS position reports. The rate in degrees per
+    /// minute is (value / 4.733)².
+    pub fn new(raw: i8) -> RateOfTurn {
+        match raw {
+            -128 => RateOfTurn::NotAvailable,
+            -127 => RateOfTurn::HardLeft,
+            127 => RateOfTurn::HardRight,
+            _ => {
+                let rate = (raw as f64 / 4.733).powi(2);
+                if raw < 0 {
+                    RateOfTurn::TurningLeft(rate)
+                } else {
+                    RateOfTurn::TurningRight(rate)
+                }
+            }
+        }
+    }
+
+    /// Return the rate of turn in degrees per minute, negative when turning left. Returns
+    /// `None` if the rate isn't available or known.
+    pub fn degrees_per_minute(&self) -> Option<f64> {
+        match self {
+            RateOfTurn::TurningRight(rate) => Some(*rate),
+            RateOfTurn::TurningLeft(rate) => Some(-rate),
+            _ => None,
+        }
+    }
+}
+
+/// Communication state of a position report (19 bits). Which of the two layouts the field has
+/// depends on the message type, or on the communication state selector flag of type 18.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum CommunicationState {
+    /// Self-organized TDMA communication state
+    Sotdma {
+        /// Synchronization state (2 bits): 0 = UTC direct, 1 = UTC indirect, 2 = synchronized
+        /// to a base station, 3 = synchronized to another station
+        sync_state: u8,
+
+        /// Frames remaining until a new slot is selected (3 bits)
+        slot_timeout: u8,
+
+        /// Sub message (14 bits) whose meaning depends on the slot timeout: received stations
+        /// (3, 5 and 7), slot number (2, 4 and 6), UTC hour and minute (1) or slot offset (0)
+        sub_message: u16,
+    },
+
+    /// Incremental TDMA communication state
+    Itdma {
+        /// Synchronization state (2 bits), see `Sotdma`
+        sync_state: u8,
+
+        /// Offset to the next slot to be used (13 bits)
+        slot_increment: u16,
+
+        /// Number of consecutive slots to allocate (3 bits)
+        number_of_slots: u8,
+
+        /// True if the slot allocation is kept for one more frame
+        keep_flag: bool,
+    },
+}
+
+impl CommunicationState {
+    /// Decode the 19-bit communication state field using the SOTDMA or ITDMA layout.
+    pub fn new(radio_status: u32, itdma: bool) -> CommunicationState {
+        let sync_state = ((radio_status >> 17) & 0b11) as u8;
+        if itdma {
+            CommunicationState::Itdma {
+                sync_state,
+                slot_increment: ((radio_status >> 4) & 0x1fff) as u16,
+                number_of_slots: ((radio_status >> 1) & 0b111) as u8,
+                keep_flag: radio_status & 1 != 0,
+            }
+        } else {
+            CommunicationState::Sotdma {
+                sync_state,
+                slot_timeout: ((radio_status >> 14) & 0b111) as u8,
+                sub_message: (radio_status & 0x3fff) as u16,
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// Types 5 and 24: Ship static voyage related data, and boat static data report.
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VesselStaticData {
     /// True if the data is about own vessel, false if about other vessel.
     pub own_vessel: bool,
@@ -408,6 +667,12 @@ pub struct VesselStaticData {
     /// Class A or Class B
     pub ais_type: AisClass,
 
+    /// Message type the data was decoded from (5 or 24)
+    pub message_type: u8,
+
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
@@ -451,6 +716,7 @@ pub struct VesselStaticData {
     pub position_fix_type: Option<PositionFixType>,
 
     /// ETA (20 bits)
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub eta: Option<DateTime<Utc>>,
 
     /// Maximum present static draught in decimetres (1-255; 8 bits)
@@ -467,6 +733,7 @@ pub struct VesselStaticData {
 
 /// Ship type derived from combined ship and cargo type field
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum ShipType {
     NotAvailable = 0,             // 0
     Reserved1 = 10,               // 1x
@@ -591,6 +858,7 @@ impl core::fmt::Display for ShipType {
 
 /// Cargo type derived from combined ship and cargo type field
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum CargoType {
     Undefined = 10,          // x0
     HazardousCategoryA = 11, // x1
@@ -657,6 +925,7 @@ impl Default for CargoType {
 
 /// EPFD position fix types
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum PositionFixType {
     Undefined = 0,                  // 0
     GPS = 1,                        // 1
@@ -711,10 +980,147 @@ impl core::fmt::Display for PositionFixType {
     }
 }
 
+// -------------------------------------------------------------------------------------------------
+
+/// Identity class encoded in MMSI (ITU-R M.585)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum MmsiCategory {
+    /// Ship station (MIDXXXXXX)
+    Ship,
+
+    /// Coast station (00MIDXXXX)
+    CoastStation,
+
+    /// Group of ships (0MIDXXXXX)
+    Group,
+
+    /// SAR aircraft (111MIDXXX)
+    SarAircraft,
+
+    /// Aid to navigation (99MIDXXXX)
+    AidToNavigation,
+
+    /// AIS search and rescue transmitter (970XXXXXX)
+    Sart,
+
+    /// Man overboard device (972XXXXXX)
+    Mob,
+
+    /// EPIRB with AIS (974XXXXXX)
+    Epirb,
+
+    /// Craft associated with a parent ship (98MIDXXXX)
+    AuxiliaryCraft,
+
+    /// Some other or invalid MMSI
+    Unknown,
+}
+
+/// Return the minimum number of bits a message of the given type has to contain in order to be
+/// decoded. Trailing spare bits are not required. Returns 0 for unknown message types.
+pub fn min_bits_for_type(message_type: u8) -> usize {
+    match message_type {
+        1..=3 => 168,
+        4 => 168,
+        5 => 423,
+        6 => 88,
+        7 => 72,
+        8 => 56,
+        9 => 168,
+        10 => 70,
+        11 => 168,
+        12 => 72,
+        13 => 72,
+        14 => 40,
+        15 => 88,
+        16 => 92,
+        17 => 80,
+        18 => 168,
+        19 => 308,
+        20 => 70,
+        21 => 271,
+        22 => 145,
+        23 => 154,
+        24 => 160,
+        25 => 40,
+        26 => 60,
+        27 => 95,
+        _ => 0,
+    }
+}
+
+/// Decode identity class from MMSI.
+pub fn mmsi_category(mmsi: u32) -> MmsiCategory {
+    if mmsi > 999_999_999 {
+        return MmsiCategory::Unknown;
+    }
+    match mmsi / 1_000_000 {
+        970 => MmsiCategory::Sart,
+        972 => MmsiCategory::Mob,
+        974 => MmsiCategory::Epirb,
+        111 => MmsiCategory::SarAircraft,
+        200..=799 => MmsiCategory::Ship,
+        _ => match mmsi / 10_000_000 {
+            99 => MmsiCategory::AidToNavigation,
+            98 => MmsiCategory::AuxiliaryCraft,
+            0 if (2_000_000..8_000_000).contains(&mmsi) => MmsiCategory::CoastStation,
+            2..=7 => MmsiCategory::Group,
+            _ => MmsiCategory::Unknown,
+        },
+    }
+}
+
+/// Extract the three-digit maritime identification digits (MID) from MMSI. The position of the
+/// MID depends on the category: MIDXXXXXX for ships, 00MIDXXXX for coast stations and
+/// 0MIDXXXXX for groups. Returns `None` for other categories.
+pub fn extract_mid(mmsi: u32) -> Option<u16> {
+    match mmsi_category(mmsi) {
+        MmsiCategory::Ship => Some((mmsi / 1_000_000) as u16),
+        MmsiCategory::CoastStation => Some((mmsi / 10_000) as u16),
+        MmsiCategory::Group => Some((mmsi / 100_000) as u16),
+        _ => None,
+    }
+}
+
+/// Format MMSI as a nine-digit zero-padded string. Values longer than nine digits are
+/// formatted as-is.
+pub fn format_mmsi(mmsi: u32) -> String {
+    format!("{:09}", mmsi)
+}
+
+impl core::fmt::Display for MmsiCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MmsiCategory::Ship => write!(f, "ship"),
+            MmsiCategory::CoastStation => write!(f, "coast station"),
+            MmsiCategory::Group => write!(f, "group"),
+            MmsiCategory::SarAircraft => write!(f, "SAR aircraft"),
+            MmsiCategory::AidToNavigation => write!(f, "aid to navigation"),
+            MmsiCategory::Sart => write!(f, "SART"),
+            MmsiCategory::Mob => write!(f, "MOB"),
+            MmsiCategory::Epirb => write!(f, "EPIRB"),
+            MmsiCategory::AuxiliaryCraft => write!(f, "auxiliary craft"),
+            MmsiCategory::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
 impl VesselStaticData {
-    /// Decode ISO 3166 country code from MID part of MMSI.
+    /// Decode identity class from MMSI.
+    pub fn mmsi_category(&self) -> MmsiCategory {
+        mmsi_category(self.mmsi)
+    }
+
+    /// Return MMSI as a nine-digit zero-padded string.
+    pub fn mmsi_string(&self) -> String {
+        format_mmsi(self.mmsi)
+    }
+
+    /// Decode ISO 3166 country code from MID part of MMSI. See `extract_mid` for the supported
+    /// MMSI categories.
     pub fn country(&self) -> Option<&'static str> {
-        match self.mmsi / 1000000 {
+        match extract_mid(self.mmsi)? {
             // Mapping generated with mid-to-iso3166.py
             201 => Some("AL"), // Albania
             202 => Some("AD"), // Andorra
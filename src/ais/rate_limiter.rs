@@ -0,0 +1,106 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+use chrono::Duration;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Stateful throttle which passes at most one position report per MMSI in the given interval.
+/// The interval is measured from the last report passed for the vessel. The time of each report
+/// is given by the caller, e.g. the reception time from an external clock or
+/// `NmeaMessage::best_timestamp`.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    last_emitted: HashMap<u32, DateTime<Utc>>,
+}
+
+impl RateLimiter {
+    /// Construct a new rate limiter which passes one report per vessel in `interval`.
+    pub fn new(interval: Duration) -> RateLimiter {
+        RateLimiter {
+            interval,
+            last_emitted: HashMap::new(),
+        }
+    }
+
+    /// Return the minimum interval between two reports of a vessel.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Forget the state of all vessels.
+    pub fn reset(&mut self) {
+        self.last_emitted.clear();
+    }
+
+    /// Feed a report received at `time` to the limiter. The report is returned if it's the first
+    /// one of the vessel or if at least the interval has elapsed since the last returned report
+    /// of the vessel. Otherwise `None` is returned.
+    pub fn accept<'a>(
+        &mut self,
+        vdd: &'a VesselDynamicData,
+        time: DateTime<Utc>,
+    ) -> Option<&'a VesselDynamicData> {
+        if let Some(last) = self.last_emitted.get(&vdd.mmsi) {
+            if time.signed_duration_since(*last) < self.interval {
+                return None;
+            }
+        }
+        self.last_emitted.insert(vdd.mmsi, time);
+        Some(vdd)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new(Duration::seconds(10));
+        let start = Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 0).unwrap();
+        let a = VesselDynamicData {
+            mmsi: 230992580,
+            ..Default::default()
+        };
+        let b = VesselDynamicData {
+            mmsi: 276009860,
+            ..Default::default()
+        };
+
+        // Reports of one vessel every two seconds
+        let passed: Vec<i64> = (0..12)
+            .filter(|i| {
+                limiter
+                    .accept(&a, start + Duration::seconds(i * 2))
+                    .is_some()
+            })
+            .map(|i| i * 2)
+            .collect();
+        assert_eq!(passed, vec![0, 10, 20]);
+
+        // Other vessels have their own interval
+        assert_eq!(limiter.accept(&b, start + Duration::seconds(22)), Some(&b));
+        assert_eq!(limiter.accept(&a, start + Duration::seconds(29)), None);
+        assert_eq!(limiter.accept(&a, start + Duration::seconds(30)), Some(&a));
+
+        limiter.reset();
+        assert_eq!(limiter.accept(&b, start + Duration::seconds(23)), Some(&b));
+    }
+}
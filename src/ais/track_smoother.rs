@@ -0,0 +1,200 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Stateful smoother which keeps an exponential moving average of speed and course over ground
+/// for each MMSI. Course is averaged along the shortest arc so that values near 0°/360° don't
+/// get pulled towards 180°.
+#[derive(Clone, Debug)]
+pub struct TrackSmoother {
+    alpha: f64,
+    tracks: HashMap<u32, SmoothedTrack>,
+}
+
+/// Smoothed state of a single vessel.
+#[derive(Clone, Copy, Debug, Default)]
+struct SmoothedTrack {
+    sog_knots: Option<f64>,
+    cog: Option<f64>,
+}
+
+impl TrackSmoother {
+    /// Construct a new smoother. Argument `alpha` is the weight of the newest report and it is
+    /// clamped to range 0.0..=1.0. Value 1.0 disables smoothing.
+    pub fn new(alpha: f64) -> TrackSmoother {
+        TrackSmoother {
+            alpha: alpha.clamp(0.0, 1.0),
+            tracks: HashMap::new(),
+        }
+    }
+
+    /// Return the weight of the newest report.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Forget the state of all vessels.
+    pub fn reset(&mut self) {
+        self.tracks.clear();
+    }
+
+    /// Feed a report to the smoother and return a copy of it with SOG and COG replaced by the
+    /// smoothed values. A report of a vessel without earlier state is returned unchanged.
+    /// Fields which are not available in the report are left as `None` and they don't affect
+    /// the state.
+    pub fn smooth(&mut self, vdd: &VesselDynamicData) -> VesselDynamicData {
+        let alpha = self.alpha;
+        let mut result = vdd.clone();
+        let track = self.tracks.entry(vdd.mmsi).or_default();
+
+        if let Some(sog) = vdd.sog_knots {
+            let smoothed = match track.sog_knots {
+                Some(prev) => prev + alpha * (sog - prev),
+                None => sog,
+            };
+            track.sog_knots = Some(smoothed);
+            result.sog_knots = Some(smoothed);
+        }
+
+        if let Some(cog) = vdd.cog {
+            let smoothed = match track.cog {
+                Some(prev) => wrap_degrees(prev + alpha * angle_difference(prev, cog)),
+                None => cog,
+            };
+            track.cog = Some(smoothed);
+            result.cog = Some(smoothed);
+        }
+
+        result
+    }
+}
+
+/// Return the signed shortest angle (-180°..180°) from `from` to `to`.
+fn angle_difference(from: f64, to: f64) -> f64 {
+    let diff = (to - from) % 360.0;
+    if diff > 180.0 {
+        diff - 360.0
+    } else if diff < -180.0 {
+        diff + 360.0
+    } else {
+        diff
+    }
+}
+
+/// Wrap the given angle to range 0°..360°.
+fn wrap_degrees(angle: f64) -> f64 {
+    let angle = angle % 360.0;
+    if angle < 0.0 {
+        angle + 360.0
+    } else {
+        angle
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_first_report_unchanged() {
+        let mut s = TrackSmoother::new(0.5);
+        let vdd = VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: Some(12.3),
+            cog: Some(45.0),
+            ..Default::default()
+        };
+        assert_eq!(s.smooth(&vdd), vdd);
+    }
+
+    #[test]
+    fn test_smooth_sog() {
+        let mut s = TrackSmoother::new(0.25);
+        s.smooth(&VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: Some(10.0),
+            cog: None,
+            ..Default::default()
+        });
+        let vdd = s.smooth(&VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: Some(14.0),
+            cog: None,
+            ..Default::default()
+        });
+        assert::close(vdd.sog_knots.unwrap_or(0.0), 11.0, 0.001);
+        assert_eq!(vdd.cog, None);
+
+        // Other vessels don't share the state
+        let vdd = s.smooth(&VesselDynamicData {
+            mmsi: 276009860,
+            sog_knots: Some(14.0),
+            cog: None,
+            ..Default::default()
+        });
+        assert_eq!(vdd.sog_knots, Some(14.0));
+    }
+
+    #[test]
+    fn test_smooth_cog_wraparound() {
+        let mut s = TrackSmoother::new(0.5);
+        s.smooth(&VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: None,
+            cog: Some(350.0),
+            ..Default::default()
+        });
+        let vdd = s.smooth(&VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: None,
+            cog: Some(10.0),
+            ..Default::default()
+        });
+        assert::close(vdd.cog.unwrap_or(180.0), 0.0, 0.001);
+
+        let vdd = s.smooth(&VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: None,
+            cog: Some(340.0),
+            ..Default::default()
+        });
+        assert::close(vdd.cog.unwrap_or(0.0), 350.0, 0.001);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut s = TrackSmoother::new(0.5);
+        s.smooth(&VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: Some(10.0),
+            cog: Some(90.0),
+            ..Default::default()
+        });
+        s.reset();
+        let vdd = VesselDynamicData {
+            mmsi: 230992580,
+            sog_knots: Some(20.0),
+            cog: Some(180.0),
+            ..Default::default()
+        };
+        assert_eq!(s.smooth(&vdd), vdd);
+    }
+}
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 10: UTC/Date Inquiry
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UtcDateInquiry {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,16 +28,28 @@ pub struct UtcDateInquiry {
     /// AIS station type.
     pub station: Station,
 
-    /// Source MMSI (30 bits)
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
+    /// Source MMSI (30 bits), the station requesting UTC/date
     pub source_mmsi: u32,
 
-    /// Destination MMSI (30 bits)
+    /// Destination MMSI (30 bits), the interrogated station which is expected to respond with
+    /// type 11 message
     pub destination_mmsi: u32,
 }
 
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 10: UTC/Date Inquiry
+///
+/// Message structure (72 bits):
+/// - Bits 0-5: Message Type (6 bits) - always 10
+/// - Bits 6-7: Repeat Indicator (2 bits)
+/// - Bits 8-37: Source MMSI (30 bits)
+/// - Bits 38-39: Spare (2 bits)
+/// - Bits 40-69: Destination MMSI (30 bits)
+/// - Bits 70-71: Spare (2 bits)
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -45,6 +58,7 @@ pub(crate) fn handle(
     Ok(ParsedMessage::UtcDateInquiry(UtcDateInquiry {
         own_vessel: { own_vessel },
         station: { station },
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         source_mmsi: { pick_u64(bv, 8, 30) as u32 },
         destination_mmsi: { pick_u64(bv, 40, 30) as u32 },
     }))
@@ -80,4 +94,28 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type10_repeated() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,B,:SLBbi0002?h,0*1C") {
+            Ok(ParsedMessage::UtcDateInquiry(udi)) => {
+                assert_eq!(udi.repeat_indicator, 2);
+                assert_eq!(udi.source_mmsi, 230992580);
+                assert_eq!(udi.destination_mmsi, 2300);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type10_too_short() {
+        let mut p = NmeaParser::new();
+        assert!(p.parse_sentence("!AIVDM,1,1,,B,:5MlU40,2*58").is_err());
+    }
 }
@@ -25,6 +25,7 @@ pub(crate) fn handle(
     Ok(ParsedMessage::UtcDateResponse(BaseStationReport {
         own_vessel: { own_vessel },
         station: { station },
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         timestamp: {
             let year = pick_u64(bv, 38, 14) as i32;
@@ -39,14 +40,21 @@ pub(crate) fn handle(
             if year == 0 || month == 0 || day == 0 || hour == 24 || minute == 60 || second == 60 {
                 None
             } else {
-                Some(parse_ymdhs(year, month, day, hour, minute, second)?)
+                // An invalid date only invalidates the timestamp of the response
+                match parse_ymdhs(year, month, day, hour, minute, second) {
+                    Ok(ts) => Some(ts),
+                    Err(e) => {
+                        warn!("Type 11 timestamp ignored: {}", e);
+                        None
+                    }
+                }
             }
         },
         high_position_accuracy: { pick_u64(bv, 78, 1) != 0 },
         latitude: {
             let lat_raw = pick_i64(bv, 107, 27) as i32;
             if lat_raw != 0x3412140 {
-                Some((lat_raw as f64) / 600000.0)
+                valid_latitude((lat_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -54,7 +62,7 @@ pub(crate) fn handle(
         longitude: {
             let lon_raw = pick_i64(bv, 79, 28) as i32;
             if lon_raw != 0x6791AC0 {
-                Some((lon_raw as f64) / 600000.0)
+                valid_longitude((lon_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -107,4 +115,49 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type11_as_type4() {
+        // Type 4 payload of test_parse_vdm_type4 with the message type changed to 11
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,;03OviQuMGCqWrRO9>E6fE700@GO,0*42") {
+            Ok(ParsedMessage::UtcDateResponse(bsr)) => {
+                assert_eq!(bsr.mmsi, 3669702);
+                assert_eq!(
+                    bsr.timestamp,
+                    Utc.with_ymd_and_hms(2007, 5, 14, 19, 57, 39).single()
+                );
+                assert!(bsr.high_position_accuracy);
+                assert::close(bsr.latitude.unwrap_or(0.0), 36.884, 0.001);
+                assert::close(bsr.longitude.unwrap_or(0.0), -76.352, 0.001);
+                assert_eq!(bsr.position_fix_type, Some(PositionFixType::Surveyed));
+                assert!(!bsr.raim_flag);
+                assert_eq!(bsr.radio_status, 67039);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type11_invalid_utc() {
+        // Month 15 is not a valid date but the rest of the response is still decoded
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,B,;028iqT47wP00wGiNbH8H0700`2H,0*1C") {
+            Ok(ParsedMessage::UtcDateResponse(bsr)) => {
+                assert_eq!(bsr.mmsi, 2240998);
+                assert_eq!(bsr.timestamp, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 12: Addressed Safety-Related Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct AddressedSafetyRelatedMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,10 +28,13 @@ pub struct AddressedSafetyRelatedMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub source_mmsi: u32,
 
-    /// Sequence number (2 bits)
+    /// Sequence number (2 bits). Type 13 acknowledgements refer to the message with it.
     pub sequence_number: u8,
 
     /// Destination MMSI (30 bits)
@@ -39,7 +43,7 @@ pub struct AddressedSafetyRelatedMessage {
     /// Retransmit flag (1 bit)
     pub retransmit_flag: bool,
 
-    /// Text (936 bits; 1-156 chars)
+    /// Text (936 bits; 1-156 chars). Trailing padding is removed.
     pub text: String,
 }
 
@@ -55,11 +59,12 @@ pub(crate) fn handle(
         AddressedSafetyRelatedMessage {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             source_mmsi: { pick_u64(bv, 8, 30) as u32 },
             sequence_number: { pick_u64(bv, 38, 2) as u8 },
             destination_mmsi: { pick_u64(bv, 40, 30) as u32 },
             retransmit_flag: { pick_u64(bv, 70, 1) != 0 },
-            text: { pick_string(bv, 72, 156) },
+            text: { pick_string(bv, 72, core::cmp::min(bv.len().saturating_sub(72) / 6, 156)) },
         },
     ))
 }
@@ -127,4 +132,36 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type12_multipart() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,2,1,5,B,<CLBbi8nmeKV>1F971D9?>1<PG1B>9>7rP45B5<93DP3?>D19>5BPB5@?BD5,0*6D",
+        ) {
+            Ok(ParsedMessage::Incomplete) => {}
+            _ => {
+                assert!(false);
+            }
+        }
+        match p.parse_sentence("!AIVDM,2,2,5,B,4P9>P@?C9D9?>Pnhij>Phjlmm5dP;55@P3<51B,0*6C") {
+            Ok(ParsedMessage::AddressedSafetyRelatedMessage(asrm)) => {
+                assert_eq!(asrm.repeat_indicator, 1);
+                assert_eq!(asrm.source_mmsi, 230992580);
+                assert_eq!(asrm.sequence_number, 2);
+                assert_eq!(asrm.destination_mmsi, 230012345);
+                assert!(asrm.retransmit_flag);
+                assert_eq!(
+                    asrm.text,
+                    "NAVIGATIONAL WARNING: DERELICT CONTAINER REPORTED IN POSITION 6012N 02455E, KEEP CLEAR"
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 13: Safety-Related Acknowledgment
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SafetyRelatedAcknowledgement {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,31 +28,34 @@ pub struct SafetyRelatedAcknowledgement {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub mmsi: u32,
 
     /// MMSI number 1 (30 bits)
     pub mmsi1: u32,
 
-    /// MMSI sequence
+    /// Sequence number of the message of MMSI number 1 which is acknowledged
     pub mmsi1_seq: u8,
 
     /// MMSI number 2 (30 bits)
     pub mmsi2: u32,
 
-    /// MMSI sequence
+    /// Sequence number of the message of MMSI number 2 which is acknowledged
     pub mmsi2_seq: u8,
 
     /// MMSI number 3 (30 bits)
     pub mmsi3: u32,
 
-    /// MMSI sequence
+    /// Sequence number of the message of MMSI number 3 which is acknowledged
     pub mmsi3_seq: u8,
 
     /// MMSI number 4 (30 bits)
     pub mmsi4: u32,
 
-    /// MMSI sequence
+    /// Sequence number of the message of MMSI number 4 which is acknowledged
     pub mmsi4_seq: u8,
 }
 
@@ -67,6 +71,7 @@ pub(crate) fn handle(
         SafetyRelatedAcknowledgement {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             mmsi1: { pick_u64(bv, 40, 30) as u32 },
             mmsi1_seq: { pick_u64(bv, 70, 2) as u8 },
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 14: Safety-Related Broadcast Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SafetyRelatedBroadcastMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,10 +28,13 @@ pub struct SafetyRelatedBroadcastMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub mmsi: u32,
 
-    /// Text (1-161 ASCII chars)
+    /// Text (1-161 ASCII chars). Trailing padding is removed.
     pub text: String,
 }
 
@@ -46,8 +50,9 @@ pub(crate) fn handle(
         SafetyRelatedBroadcastMessage {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
-            text: { pick_string(bv, 40, 161) },
+            text: { pick_string(bv, 40, core::cmp::min(bv.len().saturating_sub(40) / 6, 161)) },
         },
     ))
 }
@@ -128,4 +133,32 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type14_multipart() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,2,1,6,A,>02<Hi1<D=E8U@F1<D=E8U@F1<D=E8U@Fr0L4hF1L58pTpN0Hu:0MDhJ0tJ0,0*04",
+        ) {
+            Ok(ParsedMessage::Incomplete) => {}
+            _ => {
+                assert!(false);
+            }
+        }
+        match p.parse_sentence("!AIVDM,2,2,6,A,HTph4pBj1LTpA>1=N3;20nu>0EQ0D=@DB1@tpTLQ@,2*66") {
+            Ok(ParsedMessage::SafetyRelatedBroadcastMessage(srbm)) => {
+                assert_eq!(srbm.mmsi, 2300100);
+                assert_eq!(
+                    srbm.text,
+                    "SECURITE SECURITE SECURITE. GALE WARNING FOR GULF OF FINLAND, WINDS SW 20 M/S EXPECTED TONIGHT"
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 15: Interrogation
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Interrogation {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -30,6 +31,9 @@ pub struct Interrogation {
     /// Interrogation case based on data length
     pub case: InterrogationCase,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub mmsi: u32,
 
@@ -58,8 +62,51 @@ pub struct Interrogation {
     pub offset2_1: Option<u16>,
 }
 
+impl Interrogation {
+    /// Return the requested message types of the interrogated stations in order.
+    pub fn requests(&self) -> Vec<InterrogationRequest> {
+        let mut requests = vec![InterrogationRequest {
+            mmsi: self.mmsi1,
+            message_type: self.type1_1,
+            slot_offset: self.offset1_1,
+        }];
+        if let (Some(message_type), Some(slot_offset)) = (self.type1_2, self.offset1_2) {
+            requests.push(InterrogationRequest {
+                mmsi: self.mmsi1,
+                message_type,
+                slot_offset,
+            });
+        }
+        if let (Some(mmsi), Some(message_type), Some(slot_offset)) =
+            (self.mmsi2, self.type2_1, self.offset2_1)
+        {
+            requests.push(InterrogationRequest {
+                mmsi,
+                message_type,
+                slot_offset,
+            });
+        }
+        requests
+    }
+}
+
+/// Message type requested from an interrogated station.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InterrogationRequest {
+    /// Interrogated MMSI
+    pub mmsi: u32,
+
+    /// Requested message type
+    pub message_type: u8,
+
+    /// Slot offset of the response
+    pub slot_offset: u16,
+}
+
 /// The four cases of interrogation, depending on data length mostly.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum InterrogationCase {
     /// One station is interrogated for one message type.
     Case1,
@@ -77,7 +124,7 @@ pub enum InterrogationCase {
 impl InterrogationCase {
     pub fn new(bv: &BitVec) -> InterrogationCase {
         let len = bv.len();
-        if len >= 160 {
+        if len >= 140 {
             if pick_u64(bv, 90, 18) == 0 {
                 // Case 3 (160 bits but without second type and second slot)
                 InterrogationCase::Case3
@@ -85,7 +132,7 @@ impl InterrogationCase {
                 // Case 4 (160 bits)
                 InterrogationCase::Case4
             }
-        } else if len >= 110 {
+        } else if len >= 108 {
             // Case 2 (110 bits)
             InterrogationCase::Case2
         } else {
@@ -98,16 +145,38 @@ impl InterrogationCase {
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 15: Interrogation
+///
+/// The message structure is:
+/// - Bits 0-5: Message Type (6 bits) = 15
+/// - Bits 6-7: Repeat Indicator (2 bits)
+/// - Bits 8-37: Source MMSI (30 bits)
+/// - Bits 40-69: First interrogated MMSI (30 bits)
+/// - Bits 70-87: First message type (6 bits) and slot offset (12 bits)
+/// - Bits 90-107: Second message type (6 bits) and slot offset (12 bits), case 2 and 4
+/// - Bits 110-139: Second interrogated MMSI (30 bits), case 3 and 4
+/// - Bits 140-157: Message type (6 bits) and slot offset (12 bits) of the second station
+///
+/// Trailing spare bits and fields of the second station beyond the message length are allowed
+/// to be missing.
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
+    if bv.len() < 88 {
+        return Err(ParseError::InvalidSentence(format!(
+            "Type 15 message too short: {} bits (minimum 88 required)",
+            bv.len()
+        )));
+    }
+
     let case = InterrogationCase::new(bv);
+    let second_request = bv.len() >= 158;
     Ok(ParsedMessage::Interrogation(Interrogation {
         own_vessel,
         station,
         case,
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         mmsi1: { pick_u64(bv, 40, 30) as u32 },
         type1_1: { pick_u64(bv, 70, 6) as u8 },
@@ -129,11 +198,15 @@ pub(crate) fn handle(
             _ => None,
         },
         type2_1: match case {
-            InterrogationCase::Case4 => Some(pick_u64(bv, 140, 6) as u8),
+            InterrogationCase::Case3 | InterrogationCase::Case4 if second_request => {
+                Some(pick_u64(bv, 140, 6) as u8)
+            }
             _ => None,
         },
         offset2_1: match case {
-            InterrogationCase::Case4 => Some(pick_u64(bv, 146, 12) as u16),
+            InterrogationCase::Case3 | InterrogationCase::Case4 if second_request => {
+                Some(pick_u64(bv, 146, 12) as u16)
+            }
             _ => None,
         },
     }))
@@ -176,4 +249,74 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type15_two_stations() {
+        let mut p = NmeaParser::new();
+
+        // Case 4
+        match p.parse_sentence("!AIVDM,1,1,,B,?03Ovn1GP<K0<P@59a3KMWfQ@I0,2*00") {
+            Ok(ps) => {
+                match ps {
+                    // The expected result
+                    ParsedMessage::Interrogation(i) => {
+                        assert_eq!(i.case, InterrogationCase::Case4);
+                        assert_eq!(i.mmsi, 3669720);
+                        assert_eq!(
+                            i.requests(),
+                            vec![
+                                InterrogationRequest {
+                                    mmsi: 367014320,
+                                    message_type: 3,
+                                    slot_offset: 516,
+                                },
+                                InterrogationRequest {
+                                    mmsi: 367014320,
+                                    message_type: 5,
+                                    slot_offset: 617,
+                                },
+                                InterrogationRequest {
+                                    mmsi: 230123450,
+                                    message_type: 5,
+                                    slot_offset: 100,
+                                },
+                            ]
+                        );
+                    }
+                    _ => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Case 3
+        match p.parse_sentence("!AIVDM,1,1,,B,?03Ovn1GP<K0<P@0003KMWfV000,2*53") {
+            Ok(ps) => {
+                match ps {
+                    // The expected result
+                    ParsedMessage::Interrogation(i) => {
+                        assert_eq!(i.case, InterrogationCase::Case3);
+                        assert_eq!(i.type1_2, None);
+                        assert_eq!(i.mmsi2, Some(230123450));
+                        assert_eq!(i.type2_1, Some(24));
+                        assert_eq!(i.offset2_1, Some(0));
+                        assert_eq!(i.requests().len(), 2);
+                    }
+                    _ => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Truncated
+        assert!(p.parse_sentence("!AIVDM,1,1,,B,?03Ovn1GP<K0,2*2D").is_err());
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 16: Assignment Mode Command
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct AssignmentModeCommand {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -31,6 +32,9 @@ pub struct AssignmentModeCommand {
     // When the message is 144 bits long it is interpreted as a channel assignled for two stations.
     pub assigned_for_single_station: bool,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub mmsi: u32,
 
@@ -67,6 +71,7 @@ pub(crate) fn handle(
             own_vessel: { own_vessel },
             station: { station },
             assigned_for_single_station: { single },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             mmsi1: { pick_u64(bv, 40, 30) as u32 },
             offset1: { pick_u64(bv, 70, 12) as u16 },
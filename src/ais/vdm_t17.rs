@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 17: DGNSS Broadcast Binary Message.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DgnssBroadcastBinaryMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,6 +28,9 @@ pub struct DgnssBroadcastBinaryMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub mmsi: u32,
 
@@ -37,6 +41,7 @@ pub struct DgnssBroadcastBinaryMessage {
     pub longitude: Option<f64>,
 
     /// Payload (80-815 bits). Note that it appears to be tied to the now obsolete RTCM2 protocol.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "json_bit_vec::serialize"))]
     pub payload: BitVec,
 }
 
@@ -52,11 +57,12 @@ pub(crate) fn handle(
         DgnssBroadcastBinaryMessage {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             latitude: {
                 let lat_raw = pick_i64(bv, 58, 17) as i32;
                 if lat_raw != 0xd548 {
-                    Some((lat_raw as f64) / 600.0)
+                    valid_latitude((lat_raw as f64) / 600.0)
                 } else {
                     None
                 }
@@ -64,7 +70,7 @@ pub(crate) fn handle(
             longitude: {
                 let lon_raw = pick_i64(bv, 40, 18) as i32;
                 if lon_raw != 0x1a838 {
-                    Some((lon_raw as f64) / 600.0)
+                    valid_longitude((lon_raw as f64) / 600.0)
                 } else {
                     None
                 }
@@ -16,6 +16,10 @@ limitations under the License.
 use super::*;
 
 /// AIS VDM/VDO type 18: Standard Class B CS Position Report
+///
+/// Bits 139-140 are reserved for regional use and bits 141-148 contain the class B flags in
+/// order: unit, display, DSC, band, Message 22, assigned mode, RAIM and communication state
+/// selector.
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -25,6 +29,8 @@ pub(crate) fn handle(
         own_vessel: { own_vessel },
         station: { station },
         ais_type: { AisClass::ClassB },
+        message_type: 18,
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         sog_knots: {
             let raw = pick_u64(bv, 46, 10);
@@ -38,7 +44,7 @@ pub(crate) fn handle(
         longitude: {
             let lon_raw = pick_i64(bv, 57, 28) as i32;
             if lon_raw != 0x6791AC0 {
-                Some((lon_raw as f64) / 600000.0)
+                valid_longitude((lon_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -46,7 +52,7 @@ pub(crate) fn handle(
         latitude: {
             let lat_raw = pick_i64(bv, 85, 27) as i32;
             if lat_raw != 0x3412140 {
-                Some((lat_raw as f64) / 600000.0)
+                valid_latitude((lat_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -68,17 +74,22 @@ pub(crate) fn handle(
             }
         },
         timestamp_seconds: pick_u64(bv, 133, 6) as u8,
-        class_b_unit_flag: { None },
-        class_b_display: Some(pick_u64(bv, 141, 1) != 0),
-        class_b_dsc: Some(pick_u64(bv, 142, 1) != 0),
-        class_b_band_flag: Some(pick_u64(bv, 143, 1) != 0),
-        class_b_msg22_flag: Some(pick_u64(bv, 144, 1) != 0),
-        class_b_mode_flag: Some(pick_u64(bv, 145, 1) != 0),
-        raim_flag: pick_u64(bv, 141, 1) != 0,
-        class_b_css_flag: { None },
+        class_b_unit_flag: Some(pick_u64(bv, 141, 1) != 0),
+        class_b_display: Some(pick_u64(bv, 142, 1) != 0),
+        class_b_dsc: Some(pick_u64(bv, 143, 1) != 0),
+        class_b_band_flag: Some(pick_u64(bv, 144, 1) != 0),
+        class_b_msg22_flag: Some(pick_u64(bv, 145, 1) != 0),
+        class_b_mode_flag: Some(pick_u64(bv, 146, 1) != 0),
+        raim_flag: pick_u64(bv, 147, 1) != 0,
+        class_b_css_flag: Some(pick_u64(bv, 148, 1) != 0),
         radio_status: Some(pick_u64(bv, 149, 19) as u32),
+        regional_reserved: Some(pick_u64(bv, 38, 8) as u8),
+        communication_state: Some(CommunicationState::new(
+            pick_u64(bv, 149, 19) as u32,
+            pick_u64(bv, 148, 1) != 0,
+        )),
         nav_status: NavigationStatus::NotDefined,
-        rot: None,
+        rot: RateOfTurn::NotAvailable,
         rot_direction: None,
         positioning_system_meta: None,
         current_gnss_position: None,
@@ -100,7 +111,7 @@ mod test {
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 338087471);
                         assert_eq!(vdd.nav_status, NavigationStatus::NotDefined);
-                        assert_eq!(vdd.rot, None);
+                        assert_eq!(vdd.rot, RateOfTurn::NotAvailable);
                         assert_eq!(vdd.rot_direction, None);
                         assert_eq!(vdd.sog_knots, Some(0.1));
                         assert!(!vdd.high_position_accuracy);
@@ -112,6 +123,13 @@ mod test {
                         assert_eq!(vdd.positioning_system_meta, None);
                         assert_eq!(vdd.special_manoeuvre, None);
                         assert!(vdd.raim_flag);
+                        assert_eq!(vdd.class_b_unit_flag, Some(true));
+                        assert_eq!(vdd.class_b_display, Some(false));
+                        assert_eq!(vdd.class_b_dsc, Some(true));
+                        assert_eq!(vdd.class_b_band_flag, Some(true));
+                        assert_eq!(vdd.class_b_msg22_flag, Some(true));
+                        assert_eq!(vdd.class_b_mode_flag, Some(false));
+                        assert_eq!(vdd.class_b_css_flag, Some(true));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -126,4 +144,77 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type18_assigned_mode() {
+        // SOTDMA unit in assigned mode
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,B52K>;h00Fc>jpUlNV@ikwpQwP06,0*50") {
+            Ok(ps) => {
+                match ps {
+                    // The expected result
+                    ParsedMessage::VesselDynamicData(vdd) => {
+                        assert_eq!(vdd.mmsi, 338087471);
+                        assert_eq!(vdd.class_b_unit_flag, Some(false));
+                        assert_eq!(vdd.class_b_mode_flag, Some(true));
+                        assert!(vdd.raim_flag);
+                    }
+                    _ => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type18_communication_state() {
+        let mut p = NmeaParser::new();
+
+        // Selector flag 0: SOTDMA
+        match p.parse_sentence("!AIVDM,1,1,,A,B52K>;h00Fc<h@5lWb0ikwpUl<05,0*2D") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.class_b_css_flag, Some(false));
+                assert_eq!(
+                    vdd.communication_state,
+                    Some(CommunicationState::Sotdma {
+                        sync_state: 0,
+                        slot_timeout: 3,
+                        sub_message: 5,
+                    })
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Selector flag 1: ITDMA
+        match p.parse_sentence("!AIVDM,1,1,,A,B52K>;h00Fc<h@5lWb0ikwpUnTlU,0*7B") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.class_b_css_flag, Some(true));
+                assert_eq!(
+                    vdd.communication_state,
+                    Some(CommunicationState::Itdma {
+                        sync_state: 1,
+                        slot_increment: 1234,
+                        number_of_slots: 2,
+                        keep_flag: true,
+                    })
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -26,18 +26,11 @@ pub(crate) fn handle(
         own_vessel: { own_vessel },
         station: { station },
         ais_type: { AisClass::ClassA },
+        message_type: { pick_u64(bv, 0, 6) as u8 },
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         nav_status: { NavigationStatus::new(pick_u64(bv, 38, 4) as u8) },
-        rot: {
-            let raw = pick_i64(bv, 42, 8);
-            if (-126..0).contains(&raw) {
-                Some(-((-raw as f64 * 708.0 / 126.0) / 4.733).powi(2))
-            } else if (0..=126).contains(&raw) {
-                Some(((raw as f64 * 708.0 / 126.0) / 4.733).powi(2))
-            } else {
-                None
-            }
-        },
+        rot: { RateOfTurn::new(pick_i64(bv, 42, 8) as i8) },
         rot_direction: {
             let raw = pick_i64(bv, 42, 8);
             if raw == -128 {
@@ -64,7 +57,7 @@ pub(crate) fn handle(
         latitude: {
             let lat_raw = pick_i64(bv, 89, 27) as i32;
             if lat_raw != 0x3412140 {
-                Some((lat_raw as f64) / 600000.0)
+                valid_latitude((lat_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -72,7 +65,7 @@ pub(crate) fn handle(
         longitude: {
             let lon_raw = pick_i64(bv, 61, 28) as i32;
             if lon_raw != 0x6791AC0 {
-                Some((lon_raw as f64) / 600000.0)
+                valid_longitude((lon_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -127,6 +120,12 @@ pub(crate) fn handle(
         class_b_mode_flag: None,
         class_b_css_flag: None,
         radio_status: { Some(pick_u64(bv, 149, 19) as u32) },
+        regional_reserved: { Some(pick_u64(bv, 145, 3) as u8) },
+        communication_state: {
+            // Types 1 and 2 use SOTDMA, type 3 ITDMA
+            let itdma = pick_u64(bv, 0, 6) == 3;
+            Some(CommunicationState::new(pick_u64(bv, 149, 19) as u32, itdma))
+        },
     }))
 }
 
@@ -144,9 +143,10 @@ mod test {
                 match ps {
                     // The expected result
                     ParsedMessage::VesselDynamicData(vdd) => {
+                        assert_eq!(vdd.repeat_indicator, 0);
                         assert_eq!(vdd.mmsi, 371798000);
                         assert_eq!(vdd.nav_status, NavigationStatus::UnderWayUsingEngine);
-                        assert_eq!(vdd.rot, None);
+                        assert_eq!(vdd.rot, RateOfTurn::HardLeft);
                         assert_eq!(vdd.rot_direction, Some(RotDirection::Port));
                         assert_eq!(vdd.sog_knots, Some(12.3));
                         assert!(vdd.high_position_accuracy);
@@ -176,6 +176,26 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_parse_vdm_type1_repeated() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,1mRTgt0PAso;90TKcjM8h6g208CQ,0*12") {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    assert_eq!(vdd.repeat_indicator, 3);
+                    assert_eq!(vdd.mmsi, 371798000);
+                    assert_eq!(vdd.sog_knots, Some(12.3));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
     #[test]
     fn test_parse_vdm_type2() {
         let mut p = NmeaParser::new();
@@ -186,7 +206,7 @@ mod test {
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 440348000);
                         assert_eq!(vdd.nav_status, NavigationStatus::UnderWayUsingEngine);
-                        assert_eq!(vdd.rot, None);
+                        assert_eq!(vdd.rot, RateOfTurn::NotAvailable);
                         assert_eq!(vdd.rot_direction, None);
                         assert_eq!(vdd.sog_knots, Some(0.0));
                         assert!(!vdd.high_position_accuracy);
@@ -226,7 +246,7 @@ mod test {
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 563808000);
                         assert_eq!(vdd.nav_status, NavigationStatus::Moored);
-                        assert_eq!(vdd.rot, Some(0.0));
+                        assert_eq!(vdd.rot, RateOfTurn::TurningRight(0.0));
                         assert_eq!(vdd.rot_direction, Some(RotDirection::Center));
                         assert_eq!(vdd.sog_knots, Some(0.0));
                         assert!(vdd.high_position_accuracy);
@@ -255,4 +275,124 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_rate_of_turn() {
+        assert_eq!(RateOfTurn::new(-128), RateOfTurn::NotAvailable);
+        assert_eq!(RateOfTurn::new(-128).degrees_per_minute(), None);
+        assert_eq!(RateOfTurn::new(127), RateOfTurn::HardRight);
+        assert_eq!(RateOfTurn::new(-127), RateOfTurn::HardLeft);
+        assert_eq!(RateOfTurn::new(127).degrees_per_minute(), None);
+
+        match RateOfTurn::new(20) {
+            RateOfTurn::TurningRight(rate) => assert::close(rate, 17.856, 0.001),
+            _ => panic!("Expected TurningRight"),
+        }
+        assert::close(
+            RateOfTurn::new(-20).degrees_per_minute().unwrap(),
+            -17.856,
+            0.001,
+        );
+        assert::close(
+            RateOfTurn::new(126).degrees_per_minute().unwrap(),
+            708.7,
+            0.1,
+        );
+    }
+
+    #[test]
+    fn test_utc_synchronized() {
+        let mut p = NmeaParser::new();
+
+        // Sync state 0: UTC direct
+        match p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.is_utc_synchronized(), Some(true));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Sync state 2: synchronized to a base station
+        match p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g218CQ,0*4B") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.is_utc_synchronized(), Some(false));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        let vdd = VesselDynamicData::default();
+        assert_eq!(vdd.is_utc_synchronized(), None);
+    }
+
+    #[test]
+    fn test_parse_vdm_type1_latitude_out_of_range() {
+        // Latitude bits decode to 90.5 degrees which isn't the "not available" value 91
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,13aEOK00001h9g0kj=H00?v00000,0*3E") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.mmsi, 244670316);
+                assert_eq!(vdd.latitude, None);
+                assert::close(vdd.longitude.unwrap_or(0.0), 24.5, 0.0001);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type1_regional_reserved() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,13aEOK00001h9g0RNJt00?v0D000,0*1C") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.regional_reserved, Some(0b101));
+                assert_eq!(vdd.special_manoeuvre, None);
+                assert!(!vdd.raim_flag);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_quality_score() {
+        let good = VesselDynamicData {
+            high_position_accuracy: true,
+            raim_flag: true,
+            sog_knots: Some(12.3),
+            cog: Some(45.0),
+            heading_true: Some(44.0),
+            timestamp_seconds: 15,
+            ..Default::default()
+        };
+        assert_eq!(good.quality_score(), 100);
+
+        // Low accuracy, no heading and timestamp not available
+        let degraded = VesselDynamicData {
+            high_position_accuracy: false,
+            heading_true: None,
+            timestamp_seconds: 60,
+            ..good.clone()
+        };
+        assert_eq!(degraded.quality_score(), 50);
+        assert!(good.quality_score() > degraded.quality_score());
+        assert_eq!(VesselDynamicData::default().quality_score(), 20);
+    }
 }
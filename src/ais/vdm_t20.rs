@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 20: Data Link Management Message
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DataLinkManagementMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -30,6 +31,9 @@ pub struct DataLinkManagementMessage {
     /// Interrogation case based on data length
     pub case: InterrogationCase,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// Source MMSI (30 bits)
     pub mmsi: u32,
 
@@ -96,6 +100,7 @@ pub(crate) fn handle(
             own_vessel,
             station,
             case,
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             offset1: { pick_u64(bv, 40, 12) as u16 },
             number1: { pick_u64(bv, 52, 4) as u8 },
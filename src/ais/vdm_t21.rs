@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 21: Aid-to-Navigation Report
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct AidToNavigationReport {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,6 +28,9 @@ pub struct AidToNavigationReport {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
@@ -81,6 +85,18 @@ pub struct AidToNavigationReport {
     pub assigned_mode_flag: bool,
 }
 
+impl AidToNavigationReport {
+    /// Return the 5-bit aid type code.
+    pub fn aid_type_code(&self) -> u8 {
+        self.aid_type.to_value()
+    }
+
+    /// Return a human-readable label of the aid type, e.g. "RACON" or "light vessel".
+    pub fn aid_type_description(&self) -> &'static str {
+        self.aid_type.description()
+    }
+}
+
 impl LatLon for AidToNavigationReport {
     fn latitude(&self) -> Option<f64> {
         self.latitude
@@ -93,6 +109,7 @@ impl LatLon for AidToNavigationReport {
 
 /// Type of navigation aid
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum NavAidType {
     /// Default, type not specified
     NotSpecified, // 0
@@ -229,6 +246,53 @@ impl NavAidType {
             _ => Err(format!("Unrecognized Nav aid type code: {}", raw).into()),
         }
     }
+
+    /// Return the 5-bit code of the aid type.
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Return a human-readable description of the aid type.
+    pub fn description(&self) -> &'static str {
+        match self {
+            NavAidType::NotSpecified => "not specified",
+            NavAidType::ReferencePoint => "reference point",
+            NavAidType::Racon => "RACON",
+            NavAidType::FixedStructure => "fixed structure",
+            NavAidType::Reserved4 => "(reserved)",
+            NavAidType::LightWithoutSectors => "light without sectors",
+            NavAidType::LightWithSectors => "light with sectors",
+            NavAidType::LeadingLightFront => "leading light front",
+            NavAidType::LeadingLightRear => "leading light rear",
+            NavAidType::BeaconCardinalNorth => "cardinal beacon, north",
+            NavAidType::BeaconCardinalEast => "cardinal beacon, east",
+            NavAidType::BeaconCardinalSouth => "cardinal beacon, south",
+            NavAidType::BeaconCardinalWest => "cardinal beacon, west",
+            NavAidType::BeaconLateralPort => "lateral beacon, port side",
+            NavAidType::BeaconLateralStarboard => "lateral beacon, starboard side",
+            NavAidType::BeaconLateralPreferredChannelPort => {
+                "lateral beacon, preferred channel, port side"
+            }
+            NavAidType::BeaconLateralPreferredChannelStarboard => {
+                "lateral beacon, preferred channel, starboard side"
+            }
+            NavAidType::BeaconIsolatedDanger => "isolated danger beacon",
+            NavAidType::BeaconSafeWater => "safe water",
+            NavAidType::BeaconSpecialMark => "special mark",
+            NavAidType::CardinalMarkNorth => "cardinal mark, north",
+            NavAidType::CardinalMarkEast => "cardinal mark, east",
+            NavAidType::CardinalMarkSouth => "cardinal mark, south",
+            NavAidType::CardinalMarkWest => "cardinal mark, west",
+            NavAidType::PortHandMark => "port hand mark",
+            NavAidType::StarboardHandMark => "starboard hand mark",
+            NavAidType::PreferredChannelPort => "preferred channel, port side",
+            NavAidType::PreferredChannelStarboard => "preferred channel, starboard side",
+            NavAidType::IsolatedDanger => "isolated danger",
+            NavAidType::SafeWater => "safe water",
+            NavAidType::SpecialMark => "special mark",
+            NavAidType::LightVessel => "light vessel",
+        }
+    }
 }
 
 impl Default for NavAidType {
@@ -239,44 +303,7 @@ impl Default for NavAidType {
 
 impl core::fmt::Display for NavAidType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            NavAidType::NotSpecified => write!(f, "not specified"),
-            NavAidType::ReferencePoint => write!(f, "reference point"),
-            NavAidType::Racon => write!(f, "RACON"),
-            NavAidType::FixedStructure => write!(f, "FixedStructure"),
-            NavAidType::Reserved4 => write!(f, "(reserved)"),
-            NavAidType::LightWithoutSectors => write!(f, "light without sectors"),
-            NavAidType::LightWithSectors => write!(f, "light with sectors"),
-            NavAidType::LeadingLightFront => write!(f, "leading light front"),
-            NavAidType::LeadingLightRear => write!(f, "leading light rear"),
-            NavAidType::BeaconCardinalNorth => write!(f, "cardinal beacon, north"),
-            NavAidType::BeaconCardinalEast => write!(f, "cardinal beacon, east"),
-            NavAidType::BeaconCardinalSouth => write!(f, "cardinal beacon, south"),
-            NavAidType::BeaconCardinalWest => write!(f, "cardinal beacon, west"),
-            NavAidType::BeaconLateralPort => write!(f, "lateral beacon, port side"),
-            NavAidType::BeaconLateralStarboard => write!(f, "lateral beacon, starboard side"),
-            NavAidType::BeaconLateralPreferredChannelPort => {
-                write!(f, "lateral beacon, preferred channel, port side")
-            }
-            NavAidType::BeaconLateralPreferredChannelStarboard => {
-                write!(f, "lateral beacon, preferred channel, starboard side")
-            }
-            NavAidType::BeaconIsolatedDanger => write!(f, "isolated danger beacon"),
-            NavAidType::BeaconSafeWater => write!(f, "safe water"),
-            NavAidType::BeaconSpecialMark => write!(f, "special mark"),
-            NavAidType::CardinalMarkNorth => write!(f, "cardinal mark, north"),
-            NavAidType::CardinalMarkEast => write!(f, "cardinal mark, east"),
-            NavAidType::CardinalMarkSouth => write!(f, "cardinal mark, south"),
-            NavAidType::CardinalMarkWest => write!(f, "cardinal mark, west"),
-            NavAidType::PortHandMark => write!(f, "port hand mark"),
-            NavAidType::StarboardHandMark => write!(f, "starboard hand mark"),
-            NavAidType::PreferredChannelPort => write!(f, "preferred channel, port side"),
-            NavAidType::PreferredChannelStarboard => write!(f, "preferred channel, starboard side"),
-            NavAidType::IsolatedDanger => write!(f, "isolated danger"),
-            NavAidType::SafeWater => write!(f, "safe water"),
-            NavAidType::SpecialMark => write!(f, "special mark"),
-            NavAidType::LightVessel => write!(f, "light vessel"),
-        }
+        write!(f, "{}", self.description())
     }
 }
 
@@ -292,6 +319,7 @@ pub(crate) fn handle(
         AidToNavigationReport {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             aid_type: {
                 NavAidType::new(pick_u64(bv, 38, 5) as u8)
@@ -307,7 +335,7 @@ pub(crate) fn handle(
             latitude: {
                 let lat_raw = pick_i64(bv, 192, 27) as i32;
                 if lat_raw != 0x3412140 {
-                    Some((lat_raw as f64) / 600000.0)
+                    valid_latitude((lat_raw as f64) / 600000.0)
                 } else {
                     None
                 }
@@ -315,7 +343,7 @@ pub(crate) fn handle(
             longitude: {
                 let lon_raw = pick_i64(bv, 164, 28) as i32;
                 if lon_raw != 0x6791AC0 {
-                    Some((lon_raw as f64) / 600000.0)
+                    valid_longitude((lon_raw as f64) / 600000.0)
                 } else {
                     None
                 }
@@ -326,7 +354,7 @@ pub(crate) fn handle(
             dimension_to_starboard: { Some(pick_u64(bv, 243, 6) as u16) },
             position_fix_type: { Some(PositionFixType::new(pick_u64(bv, 249, 4) as u8)) },
             timestamp_seconds: { pick_u64(bv, 253, 6) as u8 },
-            off_position_indicator: { pick_u64(bv, 243, 1) != 0 },
+            off_position_indicator: { pick_u64(bv, 259, 1) != 0 },
             regional: { pick_u64(bv, 260, 8) as u8 },
             raim_flag: { pick_u64(bv, 268, 1) != 0 },
             virtual_aid_flag: { pick_u64(bv, 269, 1) != 0 },
@@ -394,4 +422,30 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type21_off_position() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,E1mg=5J1T4W0h97aRh6ba84<h2d;W:Te=eLvH50```qJD44QDlp0C1DU00,2*25",
+        ) {
+            Ok(ParsedMessage::AidToNavigationReport(atnr)) => {
+                assert_eq!(atnr.mmsi, 123456789);
+                assert!(atnr.off_position_indicator);
+                assert!(!atnr.virtual_aid_flag);
+                assert!(!atnr.assigned_mode_flag);
+                assert_eq!(atnr.position_fix_type, Some(PositionFixType::GPS));
+                assert_eq!(atnr.aid_type_code(), 20);
+                assert_eq!(atnr.aid_type_description(), "cardinal mark, north");
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(NavAidType::Racon.description(), "RACON");
+        assert_eq!(NavAidType::LightVessel.to_value(), 31);
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 22: Channel Management
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChannelManagement {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,6 +28,9 @@ pub struct ChannelManagement {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
@@ -97,6 +101,7 @@ pub(crate) fn handle(
     Ok(ParsedMessage::ChannelManagement(ChannelManagement {
         own_vessel: { own_vessel },
         station: { station },
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         channel_a: { pick_u64(bv, 40, 12) as u16 },
         channel_b: { pick_u64(bv, 52, 12) as u16 },
@@ -104,28 +109,28 @@ pub(crate) fn handle(
         power: { pick_u64(bv, 68, 1) != 0 },
         ne_lat: {
             if !addressed {
-                Some(pick_i64(bv, 87, 17) as f64 / 600.0)
+                valid_latitude(pick_i64(bv, 87, 17) as f64 / 600.0)
             } else {
                 None
             }
         },
         ne_lon: {
             if !addressed {
-                Some(pick_i64(bv, 69, 18) as f64 / 600.0)
+                valid_longitude(pick_i64(bv, 69, 18) as f64 / 600.0)
             } else {
                 None
             }
         },
         sw_lat: {
             if !addressed {
-                Some(pick_i64(bv, 122, 17) as f64 / 600.0)
+                valid_latitude(pick_i64(bv, 122, 17) as f64 / 600.0)
             } else {
                 None
             }
         },
         sw_lon: {
             if !addressed {
-                Some(pick_i64(bv, 104, 18) as f64 / 600.0)
+                valid_longitude(pick_i64(bv, 104, 18) as f64 / 600.0)
             } else {
                 None
             }
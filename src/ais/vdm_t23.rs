@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 23: Group Assignment Command
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GroupAssignmentCommand {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,6 +28,9 @@ pub struct GroupAssignmentCommand {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
@@ -69,6 +73,7 @@ pub struct GroupAssignmentCommand {
 
 /// Station Type (for message type 23).
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum StationType {
     /// All types of mobiles (default)
     AllTypes,
@@ -150,9 +155,11 @@ impl StationType {
 }
 
 /// Station interval (for message type 23)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum StationInterval {
     /// As given by the autonomous mode
+    #[default]
     Autonomous,
 
     /// 10 minutes
@@ -223,17 +230,50 @@ impl StationInterval {
             _ => Err(format!("Station interval value out of range: {}", val)),
         }
     }
-}
 
-impl Default for StationInterval {
-    fn default() -> Self {
-        StationInterval::Autonomous
+    /// Return the reporting interval in seconds or `None` if the interval isn't a fixed
+    /// duration (autonomous mode, next shorter/longer interval or reserved).
+    pub fn seconds(&self) -> Option<u32> {
+        match self {
+            StationInterval::Time10min => Some(600),
+            StationInterval::Time6min => Some(360),
+            StationInterval::Time3min => Some(180),
+            StationInterval::Time1min => Some(60),
+            StationInterval::Time30sec => Some(30),
+            StationInterval::Time15sec => Some(15),
+            StationInterval::Time10sec => Some(10),
+            StationInterval::Time5sec => Some(5),
+            _ => None,
+        }
     }
 }
 
+/// Convert an encoded reporting interval (4 bits) to seconds. Returns `None` for autonomous
+/// mode, next shorter/longer interval and reserved codes.
+pub fn reporting_interval_seconds(code: u8) -> Option<u32> {
+    StationInterval::new(code).ok()?.seconds()
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 23: Group Assignment Command
+///
+/// Message structure (160 bits):
+/// - Bits 0-5: Message Type (6 bits) - always 23
+/// - Bits 6-7: Repeat Indicator (2 bits)
+/// - Bits 8-37: MMSI (30 bits)
+/// - Bits 38-39: Spare (2 bits)
+/// - Bits 40-57: NE longitude, 0.1 minutes (18 bits)
+/// - Bits 58-74: NE latitude, 0.1 minutes (17 bits)
+/// - Bits 75-92: SW longitude, 0.1 minutes (18 bits)
+/// - Bits 93-109: SW latitude, 0.1 minutes (17 bits)
+/// - Bits 110-113: Station type (4 bits)
+/// - Bits 114-121: Ship and cargo type (8 bits)
+/// - Bits 122-143: Spare (22 bits)
+/// - Bits 144-145: Tx/Rx mode (2 bits)
+/// - Bits 146-149: Reporting interval (4 bits)
+/// - Bits 150-153: Quiet time in minutes (4 bits)
+/// - Bits 154-159: Spare (6 bits)
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -243,11 +283,12 @@ pub(crate) fn handle(
         GroupAssignmentCommand {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
-            ne_lat: { Some(pick_i64(bv, 58, 17) as f64 / 600.0) },
-            ne_lon: { Some(pick_i64(bv, 40, 18) as f64 / 600.0) },
-            sw_lat: { Some(pick_i64(bv, 93, 17) as f64 / 600.0) },
-            sw_lon: { Some(pick_i64(bv, 75, 18) as f64 / 600.0) },
+            ne_lat: { valid_latitude(pick_i64(bv, 58, 17) as f64 / 600.0) },
+            ne_lon: { valid_longitude(pick_i64(bv, 40, 18) as f64 / 600.0) },
+            sw_lat: { valid_latitude(pick_i64(bv, 93, 17) as f64 / 600.0) },
+            sw_lon: { valid_longitude(pick_i64(bv, 75, 18) as f64 / 600.0) },
             station_type: StationType::new(pick_u64(bv, 110, 4) as u8)?,
             ship_type: ShipType::new(pick_u64(bv, 114, 8) as u8),
             cargo_type: CargoType::new(pick_u64(bv, 114, 8) as u8),
@@ -298,6 +339,7 @@ mod test {
                         assert_eq!(gac.cargo_type, CargoType::Undefined);
                         assert_eq!(gac.txrx, 0);
                         assert_eq!(gac.interval, StationInterval::NextShorterReportingInverval);
+                        assert_eq!(gac.interval.seconds(), None);
                         assert_eq!(gac.quiet, None);
                     }
                     ParsedMessage::Incomplete => {
@@ -313,4 +355,41 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type23_filters() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,B,G02<Hi0>Wa6S0M42<o2AP000EL0,2*20") {
+            Ok(ParsedMessage::GroupAssignmentCommand(gac)) => {
+                assert_eq!(gac.mmsi, 2300100);
+                assert::close(gac.ne_lat.unwrap_or(0.0), 60.2, 0.001);
+                assert::close(gac.ne_lon.unwrap_or(0.0), 24.95, 0.001);
+                assert::close(gac.sw_lat.unwrap_or(0.0), 60.1, 0.001);
+                assert::close(gac.sw_lon.unwrap_or(0.0), 24.8, 0.001);
+                assert_eq!(gac.station_type, StationType::AllTypesOfClassBMobile);
+                assert_eq!(gac.ship_type, ShipType::Cargo);
+                assert_eq!(gac.txrx, 1);
+                assert_eq!(gac.interval, StationInterval::Time30sec);
+                assert_eq!(gac.interval.seconds(), Some(30));
+                assert_eq!(gac.quiet, Some(7));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_reporting_interval_seconds() {
+        assert_eq!(reporting_interval_seconds(0), None);
+        assert_eq!(reporting_interval_seconds(1), Some(600));
+        assert_eq!(reporting_interval_seconds(2), Some(360));
+        assert_eq!(reporting_interval_seconds(8), Some(5));
+        assert_eq!(reporting_interval_seconds(9), None);
+        assert_eq!(reporting_interval_seconds(15), None);
+        assert_eq!(reporting_interval_seconds(16), None);
+    }
 }
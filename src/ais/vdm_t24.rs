@@ -15,7 +15,8 @@ limitations under the License.
 */
 use super::*;
 
-/// AIS VDM/VDO type 24: Static data report
+/// AIS VDM/VDO type 24: Static data report. Multi-fragment parts are reassembled by the
+/// parser before calling this, so the part A name is always complete when cached.
 pub(crate) fn handle(
     bv: &BitVec,
     _station: Station,
@@ -40,6 +41,8 @@ pub(crate) fn handle(
     let vsd = VesselStaticData {
         own_vessel,
         ais_type: AisClass::ClassB,
+        message_type: 24,
+        repeat_indicator: pick_u64(bv, 6, 2) as u8,
         mmsi: pick_u64(bv, 8, 30) as u32,
         ais_version_indicator: 0,
         imo_number: None,
@@ -146,6 +149,7 @@ pub(crate) fn handle(
         Ok(ParsedMessage::VesselStaticData(vsd.merge(&vsd2)?))
     } else {
         store.push_vsd(vsd.mmsi, vsd);
+        store.set_fragment_progress(1, 2);
         Ok(ParsedMessage::Incomplete)
     }
 }
@@ -178,6 +182,8 @@ impl VesselStaticData {
             Ok(VesselStaticData {
                 own_vessel: self.own_vessel,
                 ais_type: self.ais_type,
+                message_type: self.message_type,
+                repeat_indicator: self.repeat_indicator,
                 mmsi: self.mmsi,
                 ais_version_indicator: self.ais_version_indicator,
                 imo_number: choose_some(self.imo_number, other.imo_number),
@@ -317,4 +323,24 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type24_fragmented_part_a() {
+        let mut p = NmeaParser::new();
+
+        // Part A split into two fragments, the name is cached only after the second one
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,1,4,B,H3KMWfQ<D61=185,0*71"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.known_vessels().count(), 0);
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,2,4,B,V18E<D58<R34,2*15"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        let vessels: Vec<(&u32, &VesselStaticData)> = p.known_vessels().collect();
+        assert_eq!(vessels.len(), 1);
+        assert_eq!(*vessels[0].0, 230123450);
+        assert_eq!(vessels[0].1.name, Some("SEA SPRAY RESEARCH 1".into()));
+    }
 }
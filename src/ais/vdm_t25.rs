@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 25: Single Slot Binary Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SingleSlotBinaryMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,24 +28,43 @@ pub struct SingleSlotBinaryMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
+    /// True if the message is addressed to `dest_mmsi`, false if broadcast.
+    pub addressed: bool,
+
+    /// True if the data is preceded by the application ID `app_id`.
+    pub structured: bool,
+
     /// When 'addressed' flag is on this field contains the parsed destination MMSI.
     pub dest_mmsi: Option<u32>,
 
-    /// When 'addressed' flag is off and 'structured' flag on this field contains
-    /// application ID which consists of 10-bit DAC and 6-bit FID as in message types 6 and 8.
-    pub app_id: Option<u16>,
+    /// When 'structured' flag is on this field contains application ID `(dac, fid)` which
+    /// consists of 10-bit DAC and 6-bit FID as in message types 6 and 8.
+    pub app_id: Option<(u16, u8)>,
 
     /// Data field of length 0-128 bits.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "json_bit_vec::serialize"))]
     pub data: BitVec,
 }
 
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 25: Single Slot Binary Message
-#[allow(clippy::collapsible_if)]
+///
+/// The message structure is:
+/// - Bits 0-5: Message Type (6 bits) = 25
+/// - Bits 6-7: Repeat Indicator (2 bits)
+/// - Bits 8-37: MMSI (30 bits)
+/// - Bit 38: Addressed flag (1 bit)
+/// - Bit 39: Structured flag (1 bit)
+/// - Destination MMSI (30 bits) if addressed
+/// - Application ID (16 bits) if structured
+/// - Data (remaining bits)
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -52,37 +72,24 @@ pub(crate) fn handle(
 ) -> Result<ParsedMessage, ParseError> {
     let addressed = pick_u64(bv, 38, 1) != 0;
     let structured = pick_u64(bv, 39, 1) != 0;
+    let dest_mmsi = if addressed {
+        Some(pick_u64(bv, 40, 30) as u32)
+    } else {
+        None
+    };
+    let (app_id, offset) = pick_app_id(bv, if addressed { 70 } else { 40 }, structured);
 
     Ok(ParsedMessage::SingleSlotBinaryMessage(
         SingleSlotBinaryMessage {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
-            dest_mmsi: {
-                if addressed {
-                    Some(pick_u64(bv, 40, 30) as u32)
-                } else {
-                    None
-                }
-            },
-            app_id: {
-                if addressed {
-                    None
-                } else if structured {
-                    Some(pick_u64(bv, 70, 16) as u16)
-                } else {
-                    None
-                }
-            },
-            data: {
-                if addressed {
-                    BitVec::from_bitslice(&bv[70..max(70, bv.len())])
-                } else if structured {
-                    BitVec::from_bitslice(&bv[86..max(86, bv.len())])
-                } else {
-                    BitVec::from_bitslice(&bv[40..max(40, bv.len())])
-                }
-            },
+            addressed,
+            structured,
+            dest_mmsi,
+            app_id,
+            data: BitVec::from_bitslice(&bv[core::cmp::min(offset, bv.len())..]),
         },
     ))
 }
@@ -128,7 +135,11 @@ mod test {
                     ParsedMessage::SingleSlotBinaryMessage(ssbm) => {
                         assert_eq!(ssbm.mmsi, 563648328);
                         assert_eq!(ssbm.dest_mmsi, None);
-                        assert_eq!(ssbm.app_id, Some(16424));
+                        assert_eq!(ssbm.app_id, Some((2, 6)));
+                        assert_eq!(
+                            ssbm.app_id.map(|(dac, fid)| (dac << 6) | fid as u16),
+                            Some(134)
+                        );
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -166,4 +177,95 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type25_flags() {
+        let mut p = NmeaParser::new();
+
+        // Addressed and structured
+        match p.parse_sentence("!AIVDM,1,1,,A,I3KMWfdo4huP0@=regft,2*34") {
+            Ok(ps) => {
+                match ps {
+                    // The expected result
+                    ParsedMessage::SingleSlotBinaryMessage(ssbm) => {
+                        assert_eq!(ssbm.mmsi, 230123450);
+                        assert!(ssbm.addressed);
+                        assert!(ssbm.structured);
+                        assert_eq!(ssbm.dest_mmsi, Some(230999000));
+                        assert_eq!(ssbm.app_id, Some((1, 0)));
+                        assert_eq!(ssbm.data.len(), 34);
+                        assert_eq!(pick_u64(&ssbm.data, 0, 32), 0xDEADBEEF);
+                    }
+                    _ => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Broadcast and structured
+        match p.parse_sentence("!AIVDM,1,1,,A,I3KMWfTrjeregft,0*20") {
+            Ok(ParsedMessage::SingleSlotBinaryMessage(ssbm)) => {
+                assert_eq!(ssbm.mmsi, 230123450);
+                assert!(!ssbm.addressed);
+                assert!(ssbm.structured);
+                assert_eq!(ssbm.dest_mmsi, None);
+                assert_eq!(ssbm.app_id, Some((235, 10)));
+                assert_eq!(ssbm.data.len(), 34);
+                assert_eq!(pick_u64(&ssbm.data, 0, 32), 0xDEADBEEF);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Addressed and unstructured
+        match p.parse_sentence("!AIVDM,1,1,,A,I3KMWf`o4huSNcKsg,0*6C") {
+            Ok(ParsedMessage::SingleSlotBinaryMessage(ssbm)) => {
+                assert_eq!(ssbm.mmsi, 230123450);
+                assert!(ssbm.addressed);
+                assert!(!ssbm.structured);
+                assert_eq!(ssbm.dest_mmsi, Some(230999000));
+                assert_eq!(ssbm.app_id, None);
+                assert_eq!(ssbm.data.len(), 32);
+                assert_eq!(pick_u64(&ssbm.data, 0, 32), 0xDEADBEEF);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Broadcast and unstructured
+        match p.parse_sentence("!AIVDM,1,1,,A,I3KMWfSNcKsg,0*4A") {
+            Ok(ps) => {
+                match ps {
+                    // The expected result
+                    ParsedMessage::SingleSlotBinaryMessage(ssbm) => {
+                        assert_eq!(ssbm.mmsi, 230123450);
+                        assert!(!ssbm.addressed);
+                        assert!(!ssbm.structured);
+                        assert_eq!(ssbm.dest_mmsi, None);
+                        assert_eq!(ssbm.app_id, None);
+                        assert_eq!(ssbm.data.len(), 32);
+                        assert_eq!(pick_u64(&ssbm.data, 0, 32), 0xDEADBEEF);
+                    }
+                    _ => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
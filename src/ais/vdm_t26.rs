@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 26: Multiple Slot Binary Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MultipleSlotBinaryMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,17 +28,27 @@ pub struct MultipleSlotBinaryMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
+    /// True if the message is addressed to `dest_mmsi`, false if broadcast.
+    pub addressed: bool,
+
+    /// True if the data is preceded by the application ID `app_id`.
+    pub structured: bool,
+
     /// When 'addressed' flag is on this field contains the parsed destination MMSI.
     pub dest_mmsi: Option<u32>,
 
-    /// When 'addressed' flag is off and 'structured' flag on this field contains
-    /// application ID which consists of 10-bit DAC and 6-bit FID as in message types 6 and 8.
-    pub app_id: Option<u16>,
+    /// When 'structured' flag is on this field contains application ID `(dac, fid)` which
+    /// consists of 10-bit DAC and 6-bit FID as in message types 6 and 8.
+    pub app_id: Option<(u16, u8)>,
 
     /// Data field of length 0-1004 bits.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "json_bit_vec::serialize"))]
     pub data: BitVec,
 
     /// Radio status
@@ -46,8 +57,8 @@ pub struct MultipleSlotBinaryMessage {
 
 // -------------------------------------------------------------------------------------------------
 
-/// AIS VDM/VDO type 26: Multiple Slot Binary Message
-#[allow(clippy::collapsible_if)]
+/// AIS VDM/VDO type 26: Multiple Slot Binary Message. The layout is the same as in type 25
+/// followed by the 20-bit radio status.
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -55,38 +66,26 @@ pub(crate) fn handle(
 ) -> Result<ParsedMessage, ParseError> {
     let addressed = pick_u64(bv, 38, 1) != 0;
     let structured = pick_u64(bv, 39, 1) != 0;
+    let dest_mmsi = if addressed {
+        Some(pick_u64(bv, 40, 30) as u32)
+    } else {
+        None
+    };
+    let (app_id, offset) = pick_app_id(bv, if addressed { 70 } else { 40 }, structured);
+    let radio_offset = bv.len() - 20;
 
     Ok(ParsedMessage::MultipleSlotBinaryMessage(
         MultipleSlotBinaryMessage {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
-            dest_mmsi: {
-                if addressed {
-                    Some(pick_u64(bv, 40, 30) as u32)
-                } else {
-                    None
-                }
-            },
-            app_id: {
-                if addressed {
-                    None
-                } else if structured {
-                    Some(pick_u64(bv, 70, 16) as u16)
-                } else {
-                    None
-                }
-            },
-            data: {
-                if addressed {
-                    BitVec::from_bitslice(&bv[70..max(70, bv.len() - 20)])
-                } else if structured {
-                    BitVec::from_bitslice(&bv[86..max(86, bv.len() - 20)])
-                } else {
-                    BitVec::from_bitslice(&bv[40..max(40, bv.len() - 20)])
-                }
-            },
-            radio: { pick_u64(bv, bv.len() - 20, 20) as u32 },
+            addressed,
+            structured,
+            dest_mmsi,
+            app_id,
+            data: BitVec::from_bitslice(&bv[core::cmp::min(offset, radio_offset)..radio_offset]),
+            radio: { pick_u64(bv, radio_offset, 20) as u32 },
         },
     ))
 }
@@ -108,8 +107,14 @@ mod test {
                     // The expected result
                     ParsedMessage::MultipleSlotBinaryMessage(msbm) => {
                         assert_eq!(msbm.mmsi, 137920605);
+                        assert!(msbm.addressed);
+                        assert!(msbm.structured);
                         assert_eq!(msbm.dest_mmsi, Some(838351848));
-                        assert_eq!(msbm.app_id, None);
+                        assert_eq!(msbm.app_id, Some((368, 35)));
+                        assert_eq!(
+                            msbm.app_id.map(|(dac, fid)| (dac << 6) | fid as u16),
+                            Some(23587)
+                        );
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -132,7 +137,11 @@ mod test {
                     ParsedMessage::MultipleSlotBinaryMessage(msbm) => {
                         assert_eq!(msbm.mmsi, 285913259);
                         assert_eq!(msbm.dest_mmsi, None);
-                        assert_eq!(msbm.app_id, Some(16254));
+                        assert_eq!(msbm.app_id, Some((334, 22)));
+                        assert_eq!(
+                            msbm.app_id.map(|(dac, fid)| (dac << 6) | fid as u16),
+                            Some(21398)
+                        );
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -147,4 +156,32 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type26_broadcast_unstructured() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,J3KMWfSNcKsg0B=5,0*33") {
+            Ok(ps) => {
+                match ps {
+                    // The expected result
+                    ParsedMessage::MultipleSlotBinaryMessage(msbm) => {
+                        assert_eq!(msbm.mmsi, 230123450);
+                        assert!(!msbm.addressed);
+                        assert!(!msbm.structured);
+                        assert_eq!(msbm.dest_mmsi, None);
+                        assert_eq!(msbm.app_id, None);
+                        assert_eq!(msbm.data.len(), 36);
+                        assert_eq!(pick_u64(&msbm.data, 0, 32), 0xDEADBEEF);
+                        assert_eq!(msbm.radio, 0x12345);
+                    }
+                    _ => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -26,9 +26,11 @@ pub(crate) fn handle(
         own_vessel: { own_vessel },
         station: { station },
         ais_type: { AisClass::ClassA },
+        message_type: 27,
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         nav_status: { NavigationStatus::new(pick_u64(bv, 40, 4) as u8) },
-        rot: { None },
+        rot: { RateOfTurn::NotAvailable },
         rot_direction: { None },
         sog_knots: {
             let sog_raw = pick_u64(bv, 62, 6);
@@ -42,7 +44,7 @@ pub(crate) fn handle(
         latitude: {
             let lat_raw = pick_i64(bv, 62, 17) as i32;
             if lat_raw != 181000 {
-                Some((lat_raw as f64) / 600.0)
+                valid_latitude((lat_raw as f64) / 600.0)
             } else {
                 None
             }
@@ -50,7 +52,7 @@ pub(crate) fn handle(
         longitude: {
             let lon_raw = pick_i64(bv, 44, 18) as i32;
             if lon_raw != 181000 {
-                Some((lon_raw as f64) / 600.0)
+                valid_longitude((lon_raw as f64) / 600.0)
             } else {
                 None
             }
@@ -77,6 +79,8 @@ pub(crate) fn handle(
         class_b_mode_flag: None,
         class_b_css_flag: None,
         radio_status: None,
+        regional_reserved: None,
+        communication_state: None,
     }))
 }
 
@@ -96,7 +100,7 @@ mod test {
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 206914217);
                         assert_eq!(vdd.nav_status, NavigationStatus::NotUnderCommand);
-                        assert_eq!(vdd.rot, None);
+                        assert_eq!(vdd.rot, RateOfTurn::NotAvailable);
                         assert_eq!(vdd.rot_direction, None);
                         assert_eq!(vdd.sog_knots, Some(1.0));
                         assert!(!vdd.high_position_accuracy);
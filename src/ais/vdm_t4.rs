@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 4: Base Station Report
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct BaseStationReport {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,10 +28,14 @@ pub struct BaseStationReport {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
     /// Timestamp
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Position accuracy: true = high (<= 10 m), false = low (> 10 m)
@@ -66,6 +71,12 @@ impl LatLon for BaseStationReport {
     }
 }
 
+impl Timed for BaseStationReport {
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 4: Base Station Report
@@ -77,6 +88,7 @@ pub(crate) fn handle(
     Ok(ParsedMessage::BaseStationReport(BaseStationReport {
         own_vessel: { own_vessel },
         station: { station },
+        repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         timestamp: {
             let year = pick_u64(bv, 38, 14) as i32;
@@ -98,7 +110,7 @@ pub(crate) fn handle(
         latitude: {
             let lat_raw = pick_i64(bv, 107, 27) as i32;
             if lat_raw != 0x3412140 {
-                Some((lat_raw as f64) / 600000.0)
+                valid_latitude((lat_raw as f64) / 600000.0)
             } else {
                 None
             }
@@ -106,7 +118,7 @@ pub(crate) fn handle(
         longitude: {
             let lon_raw = pick_i64(bv, 79, 28) as i32;
             if lon_raw != 0x6791AC0 {
-                Some((lon_raw as f64) / 600000.0)
+                valid_longitude((lon_raw as f64) / 600000.0)
             } else {
                 None
             }
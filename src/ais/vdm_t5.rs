@@ -24,6 +24,8 @@ pub(crate) fn handle(
     Ok(ParsedMessage::VesselStaticData(VesselStaticData {
         own_vessel,
         ais_type: AisClass::ClassB,
+        message_type: 5,
+        repeat_indicator: pick_u64(bv, 6, 2) as u8,
         mmsi: pick_u64(bv, 8, 30) as u32,
         ais_version_indicator: pick_u64(bv, 38, 2) as u8,
         imo_number: {
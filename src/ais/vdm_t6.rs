@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 6: Binary Addressed Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct BinaryAddressedMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,13 +28,16 @@ pub struct BinaryAddressedMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
-    /// User ID (2 bits)
+    /// Sequence number (2 bits). Type 7 acknowledgements refer to the message with it.
     pub sequence_number: u8,
 
-    /// User ID (30 bits)
+    /// Destination MMSI (30 bits)
     pub destination_mmsi: u32,
 
     /// Retransmit flag
@@ -44,7 +48,26 @@ pub struct BinaryAddressedMessage {
 
     /// Functional ID, FID (6 bits)
     pub fid: u8,
-    // TODO: data (depending on DAC and FID
+
+    /// Decoded data field if the DAC and FID combination is supported.
+    pub payload: Option<Type6Payload>,
+}
+
+/// Decoded data field of a type 6 message. The variant depends on the DAC and FID.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Type6Payload {
+    /// DAC=1, FID=2: acknowledgement of an application specific addressed binary message
+    ApplicationAck {
+        /// Acknowledged designated area code (10 bits)
+        dac: u16,
+
+        /// Acknowledged functional ID (6 bits)
+        fid: u8,
+
+        /// Text sequence number of the acknowledged message (11 bits)
+        text_sequence_number: u16,
+    },
 }
 
 impl LatLon for BinaryAddressedMessage {
@@ -59,27 +82,44 @@ impl LatLon for BinaryAddressedMessage {
 
 // -------------------------------------------------------------------------------------------------
 
-/// AIS VDM/VDO type 6: Binary Addressed Message. Implementation of the 920-bit data field is
-/// unimplemented currently.
+/// AIS VDM/VDO type 6: Binary Addressed Message. Only some formats of the 920-bit data field
+/// are decoded.
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
+    let (app_id, _) = pick_app_id(bv, 72, true);
+    let (dac, fid) = app_id.unwrap_or((0, 0));
     Ok(ParsedMessage::BinaryAddressedMessage(
         BinaryAddressedMessage {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             sequence_number: { pick_u64(bv, 38, 2) as u8 },
             destination_mmsi: { pick_u64(bv, 40, 30) as u32 },
             retransmit_flag: { pick_u64(bv, 70, 1) != 0 },
-            dac: { pick_u64(bv, 72, 10) as u16 },
-            fid: { pick_u64(bv, 82, 6) as u8 }, // TODO: data (depending on DAC and FID
+            dac,
+            fid,
+            payload: parse_payload(bv, dac, fid),
         },
     ))
 }
 
+/// Decode the data field starting at bit 88. Returns `None` for unsupported DAC and FID
+/// combinations and for data fields too short for the format.
+fn parse_payload(bv: &BitVec, dac: u16, fid: u8) -> Option<Type6Payload> {
+    match (dac, fid) {
+        (1, 2) if bv.len() >= 115 => Some(Type6Payload::ApplicationAck {
+            dac: pick_u64(bv, 88, 10) as u16,
+            fid: pick_u64(bv, 98, 6) as u8,
+            text_sequence_number: pick_u64(bv, 104, 11) as u16,
+        }),
+        _ => None,
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -115,4 +155,64 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type6_sequence_number() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfTo4huP040,0*62") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.mmsi, 230123450);
+                assert_eq!(bam.sequence_number, 1);
+                assert_eq!(bam.destination_mmsi, 230999000);
+                assert_eq!(bam.dac, 1);
+                assert_eq!(bam.fid, 0);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type6_application_ack() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfPo4huP0480Gqa0,0*39") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.mmsi, 230123450);
+                assert_eq!(bam.destination_mmsi, 230999000);
+                assert_eq!(bam.dac, 1);
+                assert_eq!(bam.fid, 2);
+                assert_eq!(
+                    bam.payload,
+                    Some(Type6Payload::ApplicationAck {
+                        dac: 1,
+                        fid: 31,
+                        text_sequence_number: 1234,
+                    })
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Other formats aren't decoded
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfTo4huP040,0*62") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.payload, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -30,6 +30,7 @@ pub use vdm_t8_payloads::Type8Payload;
 /// and FID (Functional ID) fields. This implementation stores the raw binary 
 /// payload for further processing by applications.
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct BinaryBroadcastMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -37,6 +38,9 @@ pub struct BinaryBroadcastMessage {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits) - MMSI of the broadcasting station
     pub mmsi: u32,
 
@@ -64,6 +68,10 @@ pub struct BinaryBroadcastMessage {
     
     /// Actual number of valid bits in the data field
     pub data_bit_length: usize,
+
+    /// Decoded data field, `Type8Payload::Unsupported` if the DAC and FID combination isn't
+    /// supported and `None` if the data field is too short for its format.
+    pub payload: Option<Type8Payload>,
 }
 
 impl LatLon for BinaryBroadcastMessage {
@@ -101,47 +109,28 @@ pub(crate) fn handle(
         )));
     }
 
+    let repeat_indicator = pick_u64(bv, 6, 2) as u8;
     let mmsi = pick_u64(bv, 8, 30) as u32;
-    let dac = pick_u64(bv, 40, 10) as u16;
-    let fid = pick_u64(bv, 50, 6) as u8;
+    let (app_id, _) = pick_app_id(bv, 40, true);
+    let (dac, fid) = app_id.unwrap_or((0, 0));
     
     // Extract the data payload (everything after bit 56)
     let data_bit_length = if bv.len() > 56 { bv.len() - 56 } else { 0 };
     
-    // Convert bits to bytes for storage
-    let mut data = Vec::new();
-    if data_bit_length > 0 {
-        let data_bits = &bv[56..];
-        
-        // Convert bits to bytes
-        // Note: We store the bits in big-endian byte order
-        let byte_count = (data_bit_length + 7) / 8;
-        data.reserve(byte_count);
-        
-        for byte_idx in 0..byte_count {
-            let bit_start = byte_idx * 8;
-            let bit_end = core::cmp::min(bit_start + 8, data_bit_length);
-            let bits_in_byte = bit_end - bit_start;
-            
-            let mut byte_val = 0u8;
-            for bit_offset in 0..bits_in_byte {
-                if data_bits[bit_start + bit_offset] {
-                    byte_val |= 1u8 << (7 - bit_offset);
-                }
-            }
-            data.push(byte_val);
-        }
-    }
+    // Convert bits to bytes for storage (big-endian bit order)
+    let data = pack_bits(&bv[56..]);
 
     Ok(ParsedMessage::BinaryBroadcastMessage(
         BinaryBroadcastMessage {
             own_vessel,
             station,
+            repeat_indicator,
             mmsi,
             dac,
             fid,
             data,
             data_bit_length,
+            payload: vdm_t8_payloads::parse_payload(dac, fid, bv, 56),
         },
     ))
 }
@@ -20,18 +20,23 @@ limitations under the License.
 //! Each DAC/FID combination has a different binary layout and interpretation.
 
 use super::*;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 // -------------------------------------------------------------------------------------------------
 
 /// Parsed payload data for Type 8 messages
 /// The variant depends on the DAC (Designated Area Code) and FID (Functional ID)
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "format")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "format"))]
 pub enum Type8Payload {
     /// DAC=1, FID=11: Meteorological and Hydrological Data (deprecated, use FID=31)
     MeteoHydro11(MeteoHydroData11),
     
+    /// DAC=1, FID=24: Extended Ship Static and Voyage Related Data
+    ExtendedShipStatic(ExtendedShipStaticData),
+
     /// DAC=1, FID=31: Meteorological and Hydrological Data (current standard)
     MeteoHydro31(MeteoHydroData31),
     
@@ -47,7 +52,8 @@ pub enum Type8Payload {
 /// DAC=1, FID=11: Meteorological/Hydrological Data (deprecated)
 /// Fixed length: 352 bits (44 bytes)
 /// This format has been deprecated by IMO in favor of FID=31
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MeteoHydroData11 {
     /// Latitude in degrees (-90.0 to +90.0), None if N/A
     pub latitude: Option<f64>,
@@ -158,12 +164,23 @@ pub struct MeteoHydroData11 {
     pub ice: Option<u8>,
 }
 
+impl LatLon for MeteoHydroData11 {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// DAC=1, FID=31: Meteorological and Hydrological Data (current standard)
 /// Fixed length: 360 bits (45 bytes)
 /// Supersedes FID=11 with better precision and clearer N/A values
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MeteoHydroData31 {
     /// Longitude in degrees (-180.0 to +180.0), None if N/A
     pub longitude: Option<f64>,
@@ -282,10 +299,72 @@ pub struct MeteoHydroData31 {
 
 // -------------------------------------------------------------------------------------------------
 
+/// DAC=1, FID=24: Extended Ship Static and Voyage Related Data (IMO SN.1/Circ.289)
+/// Fixed length: 278 bits
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtendedShipStaticData {
+    /// Message linkage ID which links the message to related messages (10 bits)
+    pub link_id: u16,
+
+    /// Air draught in meters (0.1-819.1), None if N/A. 819.1 means 819.1 m or more.
+    pub air_draught: Option<f64>,
+
+    /// UN/LOCODE of the last port of call, None if N/A
+    pub last_port: Option<String>,
+
+    /// UN/LOCODE of the next port of call, None if N/A
+    pub next_port: Option<String>,
+
+    /// UN/LOCODE of the second next port of call, None if N/A
+    pub second_port: Option<String>,
+
+    /// Status of SOLAS equipment, two bits for each of the 13 equipment types (26 bits)
+    pub solas_status: u32,
+
+    /// Ice class (0-14), None if N/A
+    pub ice_class: Option<u8>,
+
+    /// Shaft horse power, None if N/A
+    pub shaft_horse_power: Option<u32>,
+
+    /// VHF working channel number, None if N/A
+    pub vhf_channel: Option<u16>,
+
+    /// Lloyd's ship type, None if N/A
+    pub lloyds_ship_type: Option<String>,
+
+    /// Gross tonnage, None if N/A
+    pub gross_tonnage: Option<u32>,
+
+    /// Laden or ballast: true = laden, false = ballast, None if N/A
+    pub laden: Option<bool>,
+
+    /// Heavy fuel oil on board, None if N/A
+    pub heavy_fuel_oil: Option<bool>,
+
+    /// Light fuel oil on board, None if N/A
+    pub light_fuel_oil: Option<bool>,
+
+    /// Diesel oil on board, None if N/A
+    pub diesel_oil: Option<bool>,
+
+    /// Total amount of bunker oil in tonnes, None if N/A
+    pub bunker_oil: Option<u16>,
+
+    /// Number of persons on board, None if N/A. 8191 means 8191 or more.
+    pub persons_on_board: Option<u16>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// Parse Type 8 payload based on DAC and FID
 pub fn parse_payload(dac: u16, fid: u8, bv: &BitVec, bit_offset: usize) -> Option<Type8Payload> {
     match (dac, fid) {
         (1, 11) => parse_meteo_hydro_11(bv, bit_offset).map(Type8Payload::MeteoHydro11),
+        (1, 24) => {
+            parse_extended_ship_static(bv, bit_offset).map(Type8Payload::ExtendedShipStatic)
+        }
         (1, 31) => parse_meteo_hydro_31(bv, bit_offset).map(Type8Payload::MeteoHydro31),
         _ => Some(Type8Payload::Unsupported { dac, fid }),
     }
@@ -305,15 +384,15 @@ fn parse_meteo_hydro_11(bv: &BitVec, offset: usize) -> Option<MeteoHydroData11>
     let latitude = if lat_raw == 0x7FFFFF {
         None
     } else {
-        Some((lat_raw as f64) / 60000.0) // Convert from minutes*1000 to degrees
+        valid_latitude((lat_raw as f64) / 60000.0) // Convert from minutes*1000 to degrees
     };
     
     // Longitude: bits 80-104 (25 bits), signed, minutes * 0.001
     let lon_raw = pick_i64(bv, offset + 24, 25);
-    let longitude = if lon_raw == 0x1FFFFFF {
+    let longitude = if pick_u64(bv, offset + 24, 25) == 0x1FFFFFF {
         None
     } else {
-        Some((lon_raw as f64) / 60000.0) // Convert from minutes*1000 to degrees
+        valid_longitude((lon_raw as f64) / 60000.0) // Convert from minutes*1000 to degrees
     };
     
     // Day: bits 105-109 (5 bits)
@@ -550,6 +629,112 @@ fn parse_meteo_hydro_11(bv: &BitVec, offset: usize) -> Option<MeteoHydroData11>
 
 // -------------------------------------------------------------------------------------------------
 
+/// Parse DAC=1, FID=24 payload (278 bits starting at bit_offset)
+fn parse_extended_ship_static(bv: &BitVec, offset: usize) -> Option<ExtendedShipStaticData> {
+    if bv.len() < offset + 278 {
+        return None;
+    }
+
+    // Port of call: 5 six-bit characters, "@@@@@" if N/A
+    let pick_port = |index: usize| {
+        let port = pick_string(bv, offset + index, 5);
+        if port.is_empty() {
+            None
+        } else {
+            Some(port)
+        }
+    };
+
+    // Oil type flags: 0 = N/A, 1 = no, 2 = yes, 3 = reserved
+    let pick_oil = |index: usize| match pick_u64(bv, offset + index, 2) {
+        1 => Some(false),
+        2 => Some(true),
+        _ => None,
+    };
+
+    Some(ExtendedShipStaticData {
+        link_id: pick_u64(bv, offset, 10) as u16,
+        air_draught: {
+            let raw = pick_u64(bv, offset + 10, 13);
+            if raw == 0 {
+                None
+            } else {
+                Some((raw as f64) * 0.1)
+            }
+        },
+        last_port: pick_port(23),
+        next_port: pick_port(53),
+        second_port: pick_port(83),
+        solas_status: pick_u64(bv, offset + 113, 26) as u32,
+        ice_class: {
+            let raw = pick_u64(bv, offset + 139, 4) as u8;
+            if raw == 15 {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+        shaft_horse_power: {
+            let raw = pick_u64(bv, offset + 143, 18) as u32;
+            if raw == 0 {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+        vhf_channel: {
+            let raw = pick_u64(bv, offset + 161, 12) as u16;
+            if raw == 0 {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+        lloyds_ship_type: {
+            let raw = pick_string(bv, offset + 173, 7);
+            if raw.is_empty() {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+        gross_tonnage: {
+            let raw = pick_u64(bv, offset + 215, 18) as u32;
+            if raw == 0 {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+        laden: match pick_u64(bv, offset + 233, 2) {
+            1 => Some(true),
+            2 => Some(false),
+            _ => None,
+        },
+        heavy_fuel_oil: pick_oil(235),
+        light_fuel_oil: pick_oil(237),
+        diesel_oil: pick_oil(239),
+        bunker_oil: {
+            let raw = pick_u64(bv, offset + 241, 14) as u16;
+            if raw == 0 {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+        persons_on_board: {
+            let raw = pick_u64(bv, offset + 255, 13) as u16;
+            if raw == 0 {
+                None
+            } else {
+                Some(raw)
+            }
+        },
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// Parse DAC=1, FID=31 payload (360 bits starting at bit_offset)
 fn parse_meteo_hydro_31(bv: &BitVec, offset: usize) -> Option<MeteoHydroData31> {
     // Need 360 bits for complete message
@@ -564,3 +749,117 @@ fn parse_meteo_hydro_31(bv: &BitVec, offset: usize) -> Option<MeteoHydroData31>
     // TODO: Implement full FID=31 parsing following specification
     None
 }
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_meteo_hydro_11_lat_lon() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence(
+                "!AIVDM,2,1,3,A,802<HT@0BkMSd?CV=3n?0000000000000000000000000000000000000000,0*5A"
+            ),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("!AIVDM,2,2,3,A,00000000,0*15") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => match bbm.payload {
+                Some(Type8Payload::MeteoHydro11(mh)) => {
+                    // 60.5° N, 24.25° W
+                    assert::close(mh.latitude().unwrap_or(0.0), 60.5, 0.00001);
+                    assert::close(mh.longitude().unwrap_or(0.0), -24.25, 0.00001);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Not available
+        assert_eq!(
+            p.parse_sentence(
+                "!AIVDM,2,1,4,A,802<HT@0Bowwwwwwwsn?0000000000000000000000000000000000000000,0*03"
+            ),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("!AIVDM,2,2,4,A,00000000,0*12") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => match bbm.payload {
+                Some(Type8Payload::MeteoHydro11(mh)) => {
+                    assert_eq!(mh.latitude(), None);
+                    assert_eq!(mh.longitude(), None);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_extended_ship_static() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,83LBbi00F0b2dS4T2V9Rab7P000000007P00080pqP0001:><P001p00,2*16",
+        ) {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => match bbm.payload {
+                Some(Type8Payload::ExtendedShipStatic(ess)) => {
+                    assert_eq!(ess.link_id, 42);
+                    assert::close(ess.air_draught.unwrap_or(0.0), 34.5, 0.001);
+                    assert_eq!(ess.last_port, Some("FIHEL".to_string()));
+                    assert_eq!(ess.next_port, Some("SESTO".to_string()));
+                    assert_eq!(ess.second_port, None);
+                    assert_eq!(ess.ice_class, None);
+                    assert_eq!(ess.shaft_horse_power, None);
+                    assert_eq!(ess.vhf_channel, Some(16));
+                    assert_eq!(ess.lloyds_ship_type, Some("A13".to_string()));
+                    assert_eq!(ess.gross_tonnage, Some(9500));
+                    assert_eq!(ess.laden, Some(true));
+                    assert_eq!(ess.heavy_fuel_oil, Some(true));
+                    assert_eq!(ess.light_fuel_oil, Some(false));
+                    assert_eq!(ess.diesel_oil, None);
+                    assert_eq!(ess.bunker_oil, None);
+                    assert_eq!(ess.persons_on_board, Some(120));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Too short
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,83LBbi00F0b2dS4T2V9Rab7P000000007P00080pqP0001:><P001p0,0*24",
+        ) {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.payload, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 9: Standard SAR Aircraft Position Report
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct StandardSarAircraftPositionReport {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -27,13 +28,18 @@ pub struct StandardSarAircraftPositionReport {
     /// AIS station type.
     pub station: Station,
 
+    /// Repeat indicator (2 bits)
+    pub repeat_indicator: u8,
+
     /// User ID (30 bits)
     pub mmsi: u32,
 
-    /// Altitude
+    /// Altitude in metres (12 bits). Value 4094 means 4094 metres or higher, `None` when not
+    /// available.
     pub altitude: Option<u16>,
 
-    /// Speed over ground in knots. Value 1022 means 1022 knots or more.
+    /// Speed over ground in knots (10 bits). Value 1022 means 1022 knots or more, `None` when
+    /// not available.
     pub sog_knots: Option<u16>,
 
     /// Position accuracy: true = high (<= 10 m), false = low (> 10 m)
@@ -93,6 +99,7 @@ pub(crate) fn handle(
         StandardSarAircraftPositionReport {
             own_vessel: { own_vessel },
             station: { station },
+            repeat_indicator: { pick_u64(bv, 6, 2) as u8 },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
             altitude: {
                 let raw = pick_u64(bv, 38, 12) as u16;
@@ -114,7 +121,7 @@ pub(crate) fn handle(
             latitude: {
                 let lat_raw = pick_i64(bv, 89, 27) as i32;
                 if lat_raw != 0x3412140 {
-                    Some((lat_raw as f64) / 600000.0)
+                    valid_latitude((lat_raw as f64) / 600000.0)
                 } else {
                     None
                 }
@@ -122,7 +129,7 @@ pub(crate) fn handle(
             longitude: {
                 let lon_raw = pick_i64(bv, 61, 28) as i32;
                 if lon_raw != 0x6791AC0 {
-                    Some((lon_raw as f64) / 600000.0)
+                    valid_longitude((lon_raw as f64) / 600000.0)
                 } else {
                     None
                 }
@@ -186,4 +193,24 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type9_not_available() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,B,91b55wwwwwOS@OhQAC062Ch2089h,0*64") {
+            Ok(ParsedMessage::StandardSarAircraftPositionReport(sapr)) => {
+                assert_eq!(sapr.mmsi, 111232511);
+                assert_eq!(sapr.altitude, None);
+                assert_eq!(sapr.sog_knots, None);
+                assert::close(sapr.longitude().unwrap_or(0.0), -6.27884, 0.00001);
+                assert::close(sapr.latitude().unwrap_or(0.0), 58.144, 0.00001);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
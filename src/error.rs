@@ -30,6 +30,15 @@ pub enum ParseError {
 
     /// The sentence format isn't what expected
     InvalidSentence(String),
+
+    /// The sentence is a part of a multipart message which isn't complete yet. Returned only by
+    /// `NmeaParser::parse_sentence_strict`. The counts are zero if the parser can't tell them.
+    NeedMoreFragments {
+        /// Number of fragments received so far
+        have: u8,
+        /// Total number of fragments in the message
+        need: u8,
+    },
 }
 
 impl From<String> for ParseError {
@@ -58,6 +67,9 @@ impl fmt::Display for ParseError {
             }
             ParseError::CorruptedSentence(s) => write!(f, "Corrupted NMEA sentence: {}", s),
             ParseError::InvalidSentence(s) => write!(f, "Invalid NMEA sentence: {}", s),
+            ParseError::NeedMoreFragments { have, need } => {
+                write!(f, "Incomplete multipart message: {} of {} fragments received", have, need)
+            }
         }
     }
 }
@@ -0,0 +1,109 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// AAM - Waypoint arrival alarm
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AamData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// True if the arrival circle has been entered
+    pub circle_entered: Option<bool>,
+
+    /// True if the perpendicular has been passed at the waypoint
+    pub perpendicular_passed: Option<bool>,
+
+    /// Arrival circle radius
+    pub circle_radius: Option<f64>,
+
+    /// Unit of the arrival circle radius (`N` = nautical miles)
+    pub radius_units: Option<char>,
+
+    /// Waypoint ID
+    pub waypoint_id: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxAAM: Waypoint arrival alarm
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Aam(AamData {
+        source: nav_system,
+        circle_entered: parse_status_flag(&split, 1, "AAM status")?,
+        perpendicular_passed: parse_status_flag(&split, 2, "AAM status")?,
+        circle_radius: pick_number_field(&split, 3)?,
+        radius_units: split.get(4).and_then(|s| s.chars().next()),
+        waypoint_id: pick_string_field(&split, 5),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpaam() {
+        match NmeaParser::new().parse_sentence("$GPAAM,A,A,0.10,N,WPTNME*32") {
+            Ok(ps) => match ps {
+                ParsedMessage::Aam(aam) => {
+                    assert_eq!(aam.source, NavigationSystem::Gps);
+                    assert_eq!(aam.circle_entered, Some(true));
+                    assert_eq!(aam.perpendicular_passed, Some(true));
+                    assert_eq!(aam.circle_radius, Some(0.1));
+                    assert_eq!(aam.radius_units, Some('N'));
+                    assert_eq!(aam.waypoint_id, Some("WPTNME".into()));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gpaam_not_arrived() {
+        match NmeaParser::new().parse_sentence("$GPAAM,V,V,,,*76") {
+            Ok(ps) => match ps {
+                ParsedMessage::Aam(aam) => {
+                    assert_eq!(aam.circle_entered, Some(false));
+                    assert_eq!(aam.perpendicular_passed, Some(false));
+                    assert_eq!(aam.circle_radius, None);
+                    assert_eq!(aam.radius_units, None);
+                    assert_eq!(aam.waypoint_id, None);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// ACK - Acknowledge alarm
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AckData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Unique alarm number (identifier) of the acknowledged alarm, see `AlrData::alarm_id`
+    pub alarm_id: Option<u16>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxACK: Acknowledge alarm
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Ack(AckData {
+        source: nav_system,
+        alarm_id: pick_number_field(&split, 1)?,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_ack() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$IIACK,031*57") {
+            Ok(ParsedMessage::Ack(ack)) => {
+                assert_eq!(ack.alarm_id, Some(31));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        match p.parse_sentence("$IIACK,*65") {
+            Ok(ParsedMessage::Ack(ack)) => {
+                assert_eq!(ack.alarm_id, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
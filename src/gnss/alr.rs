@@ -0,0 +1,118 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// ALR - Set alarm state
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AlrData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of the alarm condition change. Date is set to 2000-01-01.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Unique alarm number (identifier) at the alarm source
+    pub alarm_id: Option<u16>,
+
+    /// Alarm condition: true = threshold exceeded, false = not exceeded
+    pub active: Option<bool>,
+
+    /// Alarm acknowledge state: true = acknowledged, false = unacknowledged
+    pub acknowledged: Option<bool>,
+
+    /// Alarm description text
+    pub description: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxALR: Set alarm state
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Alr(AlrData {
+        source: nav_system,
+        timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
+        alarm_id: pick_number_field(&split, 2)?,
+        active: parse_status_flag(&split, 3, "ALR condition")?,
+        acknowledged: parse_status_flag(&split, 4, "ALR acknowledge state")?,
+        description: pick_string_field(&split, 5),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_alr() {
+        match NmeaParser::new().parse_sentence("$IIALR,143512.50,031,A,V,BILGE LEVEL HIGH*60") {
+            Ok(ParsedMessage::Alr(alr)) => {
+                assert_eq!(
+                    alr.timestamp,
+                    Utc.with_ymd_and_hms(2000, 1, 1, 14, 35, 12)
+                        .single()
+                        .map(|t| t + chrono::Duration::milliseconds(500))
+                );
+                assert_eq!(alr.alarm_id, Some(31));
+                assert_eq!(alr.active, Some(true));
+                assert_eq!(alr.acknowledged, Some(false));
+                assert_eq!(alr.description, Some("BILGE LEVEL HIGH".into()));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_alr_empty_and_invalid_fields() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$IIALR,,,,,*73") {
+            Ok(ParsedMessage::Alr(alr)) => {
+                assert_eq!(alr.timestamp, None);
+                assert_eq!(alr.alarm_id, None);
+                assert_eq!(alr.active, None);
+                assert_eq!(alr.acknowledged, None);
+                assert_eq!(alr.description, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(
+            p.parse_sentence("$IIALR,143512.50,031,A,X,BILGE*36"),
+            Err(ParseError::InvalidSentence(
+                "Invalid ALR acknowledge state: X".into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,121 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// BOD - Bearing origin to destination
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BodData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Bearing from origin to destination, degrees true
+    pub bearing_true: Option<f64>,
+
+    /// Bearing from origin to destination, degrees magnetic
+    pub bearing_magnetic: Option<f64>,
+
+    /// Destination waypoint ID
+    pub destination_id: Option<String>,
+
+    /// Origin waypoint ID
+    pub origin_id: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxBOD: Bearing origin to destination
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Bod(BodData {
+        source: nav_system,
+        bearing_true: pick_number_field(&split, 1)?,
+        bearing_magnetic: pick_number_field(&split, 3)?,
+        destination_id: pick_string_field(&split, 5),
+        origin_id: pick_string_field(&split, 6),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpbod() {
+        match NmeaParser::new().parse_sentence("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45") {
+            Ok(ps) => match ps {
+                ParsedMessage::Bod(bod) => {
+                    assert_eq!(bod.source, NavigationSystem::Gps);
+                    assert_eq!(bod.bearing_true, Some(99.3));
+                    assert_eq!(bod.bearing_magnetic, Some(105.6));
+                    assert_eq!(bod.destination_id, Some("POINTB".into()));
+                    assert_eq!(bod.origin_id, Some("POINTA".into()));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gpbod_verbatim_ids() {
+        match NmeaParser::new().parse_sentence("$GPBOD,097.0,T,103.2,M,DEST 1,ORIG-A*21") {
+            Ok(ps) => match ps {
+                ParsedMessage::Bod(bod) => {
+                    assert_eq!(bod.destination_id, Some("DEST 1".into()));
+                    assert_eq!(bod.origin_id, Some("ORIG-A".into()));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gpbod_empty_fields() {
+        match NmeaParser::new().parse_sentence("$GPBOD,,T,,M,,*47") {
+            Ok(ps) => match ps {
+                ParsedMessage::Bod(bod) => {
+                    assert_eq!(bod.bearing_true, None);
+                    assert_eq!(bod.bearing_magnetic, None);
+                    assert_eq!(bod.destination_id, None);
+                    assert_eq!(bod.origin_id, None);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// DBK - Depth Below Keel
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DbkData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Water depth below keel, meters
+    pub depth_meters: Option<f64>,
+
+    /// Water depth below keel, feet
+    pub depth_feet: Option<f64>,
+
+    /// Water depth below keel, fathoms
+    pub depth_fathoms: Option<f64>,
+}
+
+impl LatLon for DbkData {
+    fn latitude(&self) -> Option<f64> {
+        None
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        None
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxDBK: Depth Below Keel
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let mut dbk = DbkData {
+        source: nav_system,
+        depth_meters: None,
+        depth_feet: None,
+        depth_fathoms: None,
+    };
+
+    parse_unit_fields(&split, 1..7, |unit, value| match unit {
+        "f" => dbk.depth_feet = value,
+        "M" => dbk.depth_meters = value,
+        "F" => dbk.depth_fathoms = value,
+        _ => {}
+    })?;
+
+    Ok(ParsedMessage::Dbk(dbk))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_dbk() {
+        match NmeaParser::new().parse_sentence("$SDDBK,11.5,f,3.5,M,1.9,F*22") {
+            Ok(ps) => match ps {
+                ParsedMessage::Dbk(dbk) => {
+                    assert_eq!(dbk.depth_meters, Some(3.5));
+                    assert_eq!(dbk.depth_feet, Some(11.5));
+                    assert_eq!(dbk.depth_fathoms, Some(1.9));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_dbk_meters_only() {
+        match NmeaParser::new().parse_sentence("$SDDBK,,f,3.5,M,,F*1F") {
+            Ok(ps) => match ps {
+                ParsedMessage::Dbk(dbk) => {
+                    assert_eq!(dbk.depth_meters, Some(3.5));
+                    assert_eq!(dbk.depth_feet, None);
+                    assert_eq!(dbk.depth_fathoms, None);
+                    assert_eq!(dbk.latitude(), None);
+                    assert_eq!(dbk.longitude(), None);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
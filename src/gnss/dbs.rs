@@ -17,8 +17,12 @@ limitations under the License.
 use super::*;
 
 /// DBS - Depth Below Surface
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DbsData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
     /// Water depth below surface, meters
     pub depth_meters: Option<f64>,
 
@@ -32,10 +36,14 @@ pub struct DbsData {
 // -------------------------------------------------------------------------------------------------
 
 /// xxDBS: Depth Below Surface
-pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Dbs(DbsData {
+        source: nav_system,
         depth_meters: pick_number_field(&split, 3)?,
         depth_feet: pick_number_field(&split, 1)?,
         depth_fathoms: pick_number_field(&split, 5)?,
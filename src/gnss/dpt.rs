@@ -17,8 +17,12 @@ limitations under the License.
 use super::*;
 
 /// DPT - Depth of Water
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DptData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
     /// Water depth relative to transducer, meters
     pub depth_relative_to_transducer: Option<f64>,
 
@@ -29,10 +33,14 @@ pub struct DptData {
 // -------------------------------------------------------------------------------------------------
 
 /// xxDPT: Depth of Water
-pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Dpt(DptData {
+        source: nav_system,
         depth_relative_to_transducer: pick_number_field(&split, 1)?,
         transducer_offset: pick_number_field(&split, 2)?,
     }))
@@ -0,0 +1,220 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// DSC - Digital Selective Calling information
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DscData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Format specifier, e.g. 12 = distress, 16 = all ships, 20 = individual call
+    pub format_specifier: Option<u8>,
+
+    /// MMSI of the addressed station, or of the calling station in distress calls
+    pub mmsi: Option<u32>,
+
+    /// Category, e.g. 00 = routine, 08 = safety, 10 = urgency, 12 = distress
+    pub category: Option<u8>,
+
+    /// Nature of distress, e.g. 00 = fire, 01 = flooding, 06 = disabled and adrift
+    pub nature_of_distress: Option<u8>,
+
+    /// First telecommand (not available in distress calls)
+    pub first_telecommand: Option<u8>,
+
+    /// Second telecommand or type of communication
+    pub second_telecommand: Option<u8>,
+
+    /// Latitude in degrees
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees
+    pub longitude: Option<f64>,
+
+    /// UTC time of the position. Date is set to 2000-01-01.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// MMSI of the vessel in distress (distress relays and acknowledgements)
+    pub distress_mmsi: Option<u32>,
+
+    /// Acknowledgement: 'R' = acknowledge request, 'B' = acknowledgement, 'S' = neither
+    pub acknowledgement: Option<char>,
+
+    /// True if a DSE expansion sentence follows
+    pub expansion: bool,
+}
+
+impl LatLon for DscData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Format specifier of distress calls
+const DSC_FORMAT_DISTRESS: u8 = 12;
+
+/// xxDSC: Digital Selective Calling information
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let format_specifier = pick_number_field(&split, 1)?;
+    let distress = format_specifier == Some(DSC_FORMAT_DISTRESS);
+    let (latitude, longitude) = parse_dsc_position(split.get(6).unwrap_or(&""))?;
+
+    Ok(ParsedMessage::Dsc(DscData {
+        source: nav_system,
+        format_specifier,
+        mmsi: parse_dsc_address(split.get(2).unwrap_or(&""))?,
+        category: pick_number_field(&split, 3)?,
+        nature_of_distress: if distress {
+            pick_number_field(&split, 4)?
+        } else {
+            pick_number_field(&split, 9)?
+        },
+        first_telecommand: if distress {
+            None
+        } else {
+            pick_number_field(&split, 4)?
+        },
+        second_telecommand: pick_number_field(&split, 5)?,
+        latitude,
+        longitude,
+        timestamp: {
+            let hhmm = split.get(7).unwrap_or(&"");
+            if hhmm.len() == 4 && *hhmm != "8888" {
+                Some(parse_hhmmss(&format!("{}00", hhmm), now)?)
+            } else {
+                None
+            }
+        },
+        distress_mmsi: parse_dsc_address(split.get(8).unwrap_or(&""))?,
+        acknowledgement: split.get(10).and_then(|s| s.chars().next()),
+        expansion: split.get(11).map(|s| *s == "E").unwrap_or(false),
+    }))
+}
+
+/// Decode MMSI from a 10-digit DSC address. The address consists of five pairs of digits of
+/// which the last digit is always zero for a ship station.
+fn parse_dsc_address(address: &str) -> Result<Option<u32>, ParseError> {
+    if address.is_empty() {
+        return Ok(None);
+    }
+    if address.len() != 10 || !address.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid DSC address: {}", address).into());
+    }
+    Ok(Some(address[0..9].parse::<u32>()?))
+}
+
+/// Decode position from a 10-digit DSC position field: quadrant (0 = NE, 1 = NW, 2 = SE,
+/// 3 = SW), latitude degrees and minutes (DDMM) and longitude degrees and minutes (DDDMM).
+/// Value 9999999999 means that the position is not available.
+fn parse_dsc_position(position: &str) -> Result<(Option<f64>, Option<f64>), ParseError> {
+    if position.is_empty() || position == "9999999999" {
+        return Ok((None, None));
+    }
+    if position.len() != 10 || !position.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid DSC position: {}", position).into());
+    }
+    let latitude = position[1..3].parse::<f64>()? + position[3..5].parse::<f64>()? / 60.0;
+    let longitude = position[5..8].parse::<f64>()? + position[8..10].parse::<f64>()? / 60.0;
+    match &position[0..1] {
+        "0" => Ok((Some(latitude), Some(longitude))),
+        "1" => Ok((Some(latitude), Some(-longitude))),
+        "2" => Ok((Some(-latitude), Some(longitude))),
+        "3" => Ok((Some(-latitude), Some(-longitude))),
+        _ => Err(format!("Invalid DSC position quadrant: {}", position).into()),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_dsc_distress() {
+        match NmeaParser::new().parse_sentence("$CDDSC,12,2300123450,12,06,00,0601502457,1245,,,S,E*6B")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Dsc(dsc) => {
+                    assert_eq!(dsc.format_specifier, Some(12));
+                    assert_eq!(dsc.mmsi, Some(230012345));
+                    assert_eq!(dsc.category, Some(12));
+                    assert_eq!(dsc.nature_of_distress, Some(6));
+                    assert_eq!(dsc.first_telecommand, None);
+                    assert_eq!(dsc.second_telecommand, Some(0));
+                    assert::close(dsc.latitude.unwrap_or(0.0), 60.25, 0.000001);
+                    assert::close(dsc.longitude.unwrap_or(0.0), 24.95, 0.000001);
+                    assert_eq!(
+                        dsc.timestamp,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 12, 45, 0).single()
+                    );
+                    assert_eq!(dsc.distress_mmsi, None);
+                    assert_eq!(dsc.acknowledgement, Some('S'));
+                    assert!(dsc.expansion);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_dsc_individual() {
+        match NmeaParser::new().parse_sentence("$CDDSC,20,2765432100,00,21,26,9999999999,8888,,,B,*3A")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Dsc(dsc) => {
+                    assert_eq!(dsc.format_specifier, Some(20));
+                    assert_eq!(dsc.mmsi, Some(276543210));
+                    assert_eq!(dsc.category, Some(0));
+                    assert_eq!(dsc.nature_of_distress, None);
+                    assert_eq!(dsc.first_telecommand, Some(21));
+                    assert_eq!(dsc.second_telecommand, Some(26));
+                    assert_eq!(dsc.latitude, None);
+                    assert_eq!(dsc.longitude, None);
+                    assert_eq!(dsc.timestamp, None);
+                    assert_eq!(dsc.acknowledgement, Some('B'));
+                    assert!(!dsc.expansion);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// GNSS fix consolidated from the GGA, RMC, GSA and VTG sentences of one epoch
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GnssFix {
+    /// Fix datetime. Date is 2000-01-01 if no RMC sentence was received.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Latitude in degrees
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees
+    pub longitude: Option<f64>,
+
+    /// Altitude above mean sea level in metres
+    pub altitude: Option<f64>,
+
+    /// Fix type (2D or 3D)
+    pub fix_type: Option<GsaFixMode>,
+
+    /// Number of satellites used in the fix
+    pub satellites_used: Option<u8>,
+
+    /// Horizontal dilution of precision
+    pub hdop: Option<f64>,
+
+    /// Position dilution of precision
+    pub pdop: Option<f64>,
+
+    /// Vertical dilution of precision
+    pub vdop: Option<f64>,
+
+    /// Speed over ground in knots
+    pub sog_knots: Option<f64>,
+
+    /// Course over ground in degrees (true)
+    pub cog: Option<f64>,
+}
+
+/// Maximum number of GSA messages kept for one epoch
+const MAX_GSAS: usize = 8;
+
+/// Sentence types merged by `FixAssembler`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FixSentence {
+    Gga,
+    Rmc,
+    Gsa,
+    Vtg,
+}
+
+/// Stateful assembler which merges the GGA, RMC, GSA and VTG messages of one epoch into a
+/// `GnssFix`. The type of the first message pushed is taken as the first sentence of each burst
+/// sent by the receiver, so the assembler should be fed from the start of a burst. An epoch is
+/// considered complete when a message of that type arrives again after messages of other types,
+/// when a GGA or RMC message with a different time of day arrives, or when `flush` is called.
+#[derive(Clone, Debug, Default)]
+pub struct FixAssembler {
+    fix: Option<GnssFix>,
+    gsas: Vec<GsaData>,
+    burst_start: Option<FixSentence>,
+    previous: Option<FixSentence>,
+}
+
+impl FixAssembler {
+    /// Construct an assembler without any pending epoch.
+    pub fn new() -> FixAssembler {
+        FixAssembler::default()
+    }
+
+    /// Feed a parsed message to the assembler. Returns the fix of the previous epoch when the
+    /// message starts a new one. Messages of other types are ignored.
+    pub fn push(&mut self, msg: &ParsedMessage) -> Option<GnssFix> {
+        let sentence = match msg {
+            ParsedMessage::Gga(_) => FixSentence::Gga,
+            ParsedMessage::Rmc(_) => FixSentence::Rmc,
+            ParsedMessage::Gsa(_) => FixSentence::Gsa,
+            ParsedMessage::Vtg(_) => FixSentence::Vtg,
+            _ => return None,
+        };
+        let mut completed = self.start_burst(sentence);
+        self.previous = Some(sentence);
+
+        match msg {
+            ParsedMessage::Gga(gga) => {
+                completed = completed.or_else(|| self.start_epoch(gga.timestamp));
+                let fix = self.fix.get_or_insert_with(GnssFix::default);
+                fix.timestamp = fix.timestamp.or(gga.timestamp);
+                fix.latitude = gga.latitude.or(fix.latitude);
+                fix.longitude = gga.longitude.or(fix.longitude);
+                fix.altitude = gga.altitude.or(fix.altitude);
+                fix.satellites_used = gga.satellite_count.or(fix.satellites_used);
+                fix.hdop = fix.hdop.or(gga.hdop);
+            }
+            ParsedMessage::Rmc(rmc) => {
+                completed = completed.or_else(|| self.start_epoch(rmc.timestamp));
+                let fix = self.fix.get_or_insert_with(GnssFix::default);
+                // RMC contains the date, so its timestamp is preferred
+                fix.timestamp = rmc.timestamp.or(fix.timestamp);
+                fix.latitude = fix.latitude.or(rmc.latitude);
+                fix.longitude = fix.longitude.or(rmc.longitude);
+                fix.sog_knots = fix.sog_knots.or(rmc.sog_knots);
+                fix.cog = fix.cog.or(rmc.bearing);
+            }
+            ParsedMessage::Gsa(gsa) => {
+                if self.gsas.len() >= MAX_GSAS {
+                    self.gsas.remove(0);
+                }
+                self.gsas.push(gsa.clone());
+                let fix = self.fix.get_or_insert_with(GnssFix::default);
+                if let Some(merged) = gsa::merge(&self.gsas) {
+                    fix.fix_type = merged.mode2_3d;
+                    fix.pdop = merged.pdop;
+                    fix.hdop = merged.hdop.or(fix.hdop);
+                    fix.vdop = merged.vdop;
+                    fix.satellites_used = fix
+                        .satellites_used
+                        .or(Some(merged.prn_numbers.len() as u8));
+                }
+            }
+            ParsedMessage::Vtg(vtg) => {
+                let fix = self.fix.get_or_insert_with(GnssFix::default);
+                fix.sog_knots = fix.sog_knots.or(vtg.sog_knots);
+                fix.cog = fix.cog.or(vtg.cog_true);
+            }
+            _ => {}
+        }
+        completed
+    }
+
+    /// Return the fix of the pending epoch, if any, and clear the state. The learnt first
+    /// sentence type of a burst is kept.
+    pub fn flush(&mut self) -> Option<GnssFix> {
+        self.previous = None;
+        self.complete_epoch()
+    }
+
+    /// Complete the pending epoch and return its fix.
+    fn complete_epoch(&mut self) -> Option<GnssFix> {
+        self.gsas.clear();
+        self.fix.take()
+    }
+
+    /// Check whether the given sentence type starts a new burst. If it does, the pending fix is
+    /// completed and returned. Consecutive messages of the same type, such as GSA messages of
+    /// several constellations, belong to the same burst.
+    fn start_burst(&mut self, sentence: FixSentence) -> Option<GnssFix> {
+        let burst_start = *self.burst_start.get_or_insert(sentence);
+        match self.previous {
+            Some(previous) if sentence == burst_start && previous != sentence => {
+                self.complete_epoch()
+            }
+            _ => None,
+        }
+    }
+
+    /// Check the time of a timed message against the pending epoch. If the time of day
+    /// differs, the pending fix is completed and returned.
+    fn start_epoch(&mut self, timestamp: Option<DateTime<Utc>>) -> Option<GnssFix> {
+        let time = timestamp?.time();
+        let pending = self.fix.as_ref()?.timestamp?;
+        if pending.time() != time {
+            self.complete_epoch()
+        } else {
+            None
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fix_assembler() {
+        let mut p = NmeaParser::new();
+        let mut assembler = FixAssembler::new();
+        for sentence in [
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39",
+            "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230324,003.1,W*61",
+            "$GPVTG,084.4,T,077.8,M,022.4,N,041.5,K*4A",
+        ] {
+            let msg = p.parse_sentence(sentence).unwrap();
+            assert_eq!(assembler.push(&msg), None);
+        }
+
+        // The first sentence of the next epoch completes the fix
+        let msg = p
+            .parse_sentence("$GPGGA,123520,4807.045,N,01131.010,E,1,08,0.9,545.6,M,46.9,M,,*44")
+            .unwrap();
+        let fix = assembler.push(&msg).unwrap();
+        assert_eq!(
+            fix.timestamp,
+            Utc.with_ymd_and_hms(2024, 3, 23, 12, 35, 19).single()
+        );
+        assert::close(fix.latitude.unwrap_or(0.0), 48.1173, 0.0001);
+        assert::close(fix.longitude.unwrap_or(0.0), 11.5167, 0.0001);
+        assert_eq!(fix.altitude, Some(545.4));
+        assert_eq!(fix.fix_type, Some(GsaFixMode::Fix3D));
+        assert_eq!(fix.satellites_used, Some(8));
+        assert_eq!(fix.hdop, Some(1.3));
+        assert_eq!(fix.pdop, Some(2.5));
+        assert_eq!(fix.vdop, Some(2.1));
+        assert_eq!(fix.sog_knots, Some(22.4));
+        assert_eq!(fix.cog, Some(84.4));
+
+        // The pending epoch contains only the GGA
+        let fix = assembler.flush().unwrap();
+        assert_eq!(fix.altitude, Some(545.6));
+        assert_eq!(fix.fix_type, None);
+        assert_eq!(fix.sog_knots, None);
+        assert_eq!(assembler.flush(), None);
+    }
+
+    #[test]
+    fn test_fix_assembler_burst_start() {
+        let mut p = NmeaParser::new();
+        let mut assembler = FixAssembler::new();
+        for sentence in [
+            "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39",
+            "$GPVTG,084.4,T,077.8,M,022.4,N,041.5,K*4A",
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230324,003.1,W*61",
+        ] {
+            let msg = p.parse_sentence(sentence).unwrap();
+            assert_eq!(assembler.push(&msg), None);
+        }
+
+        // The GSA of the next epoch arrives before its GGA and RMC
+        let msg = p
+            .parse_sentence("$GPGSA,A,2,04,05,09,,,,,,,,,,3.5,2.0,2.9*34")
+            .unwrap();
+        let fix = assembler.push(&msg).unwrap();
+        assert_eq!(fix.fix_type, Some(GsaFixMode::Fix3D));
+        assert_eq!(fix.pdop, Some(2.5));
+        assert_eq!(fix.hdop, Some(1.3));
+
+        let msg = p
+            .parse_sentence("$GPGGA,123520,4807.045,N,01131.010,E,1,08,0.9,545.6,M,46.9,M,,*44")
+            .unwrap();
+        assert_eq!(assembler.push(&msg), None);
+        let fix = assembler.flush().unwrap();
+        assert_eq!(fix.fix_type, Some(GsaFixMode::Fix2D));
+        assert_eq!(fix.pdop, Some(3.5));
+        assert_eq!(fix.altitude, Some(545.6));
+    }
+
+    #[test]
+    fn test_fix_assembler_gsa_limit() {
+        let mut p = NmeaParser::new();
+        let mut assembler = FixAssembler::new();
+        let msg = p
+            .parse_sentence("$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39")
+            .unwrap();
+        for _ in 0..100 {
+            assert_eq!(assembler.push(&msg), None);
+        }
+        assert_eq!(assembler.gsas.len(), MAX_GSAS);
+    }
+}
@@ -17,13 +17,14 @@ limitations under the License.
 use super::*;
 
 /// GGA - time, position, and fix related data
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GgaData {
     /// Navigation system
     pub source: NavigationSystem,
 
-    /// UTC of position fix
-    #[serde(with = "json_date_time_utc")]
+    /// UTC of position fix. Date is set to 2000-01-01.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Latitude in degrees
@@ -32,7 +33,7 @@ pub struct GgaData {
     /// Longitude in degrees
     pub longitude: Option<f64>,
 
-    /// GNSS Quality indicator
+    /// GNSS Quality indicator. The raw code is available with `GgaQualityIndicator::to_value`.
     pub quality: GgaQualityIndicator,
 
     /// Number of satellites in use
@@ -54,6 +55,13 @@ pub struct GgaData {
     pub ref_station_id: Option<u16>,
 }
 
+impl GgaData {
+    /// Height above WGS84 ellipsoid (metres) calculated from altitude and geoid separation.
+    pub fn ellipsoidal_height(&self) -> Option<f64> {
+        Some(self.altitude? + self.geoid_separation?)
+    }
+}
+
 impl LatLon for GgaData {
     fn latitude(&self) -> Option<f64> {
         self.latitude
@@ -64,8 +72,17 @@ impl LatLon for GgaData {
     }
 }
 
+/// Only the time of day is meaningful because GGA doesn't carry a date. The date is set to
+/// 2000-01-01.
+impl Timed for GgaData {
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+    }
+}
+
 /// GGA GPS quality indicator
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum GgaQualityIndicator {
     Invalid,                // 0
     GpsFix,                 // 1
@@ -76,10 +93,17 @@ pub enum GgaQualityIndicator {
     DeadReckoning,          // 6
     ManualInputMode,        // 7
     SimulationMode,         // 8
+    Other(u8),              // values not defined by the standard
 }
 
 impl GgaQualityIndicator {
+    /// Map the given code to quality indicator, same as `from_u8`.
     pub fn new(a: u8) -> GgaQualityIndicator {
+        GgaQualityIndicator::from_u8(a)
+    }
+
+    /// Map the given code to quality indicator. Codes above 8 are mapped to `Other`.
+    pub fn from_u8(a: u8) -> GgaQualityIndicator {
         match a {
             0 => GgaQualityIndicator::Invalid,
             1 => GgaQualityIndicator::GpsFix,
@@ -90,7 +114,39 @@ impl GgaQualityIndicator {
             6 => GgaQualityIndicator::DeadReckoning,
             7 => GgaQualityIndicator::ManualInputMode,
             8 => GgaQualityIndicator::SimulationMode,
-            _ => GgaQualityIndicator::Invalid,
+            _ => GgaQualityIndicator::Other(a),
+        }
+    }
+
+    /// Return the raw code of the quality indicator field.
+    pub fn to_value(&self) -> u8 {
+        match self {
+            GgaQualityIndicator::Invalid => 0,
+            GgaQualityIndicator::GpsFix => 1,
+            GgaQualityIndicator::DGpsFix => 2,
+            GgaQualityIndicator::PpsFix => 3,
+            GgaQualityIndicator::RealTimeKinematic => 4,
+            GgaQualityIndicator::RealTimeKinematicFloat => 5,
+            GgaQualityIndicator::DeadReckoning => 6,
+            GgaQualityIndicator::ManualInputMode => 7,
+            GgaQualityIndicator::SimulationMode => 8,
+            GgaQualityIndicator::Other(value) => *value,
+        }
+    }
+
+    /// Return human-readable description of the quality indicator.
+    pub fn description(&self) -> &'static str {
+        match self {
+            GgaQualityIndicator::Invalid => "invalid",
+            GgaQualityIndicator::GpsFix => "GPS fix",
+            GgaQualityIndicator::DGpsFix => "DGPS fix",
+            GgaQualityIndicator::PpsFix => "PPS fix",
+            GgaQualityIndicator::RealTimeKinematic => "Real-Time Kinematic",
+            GgaQualityIndicator::RealTimeKinematicFloat => "Real-Time Kinematic (floating point)",
+            GgaQualityIndicator::DeadReckoning => "dead reckoning",
+            GgaQualityIndicator::ManualInputMode => "manual input mode",
+            GgaQualityIndicator::SimulationMode => "simulation mode",
+            GgaQualityIndicator::Other(_) => "unknown",
         }
     }
 }
@@ -98,17 +154,8 @@ impl GgaQualityIndicator {
 impl core::fmt::Display for GgaQualityIndicator {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            GgaQualityIndicator::Invalid => write!(f, "invalid"),
-            GgaQualityIndicator::GpsFix => write!(f, "GPS fix"),
-            GgaQualityIndicator::DGpsFix => write!(f, "DGPS fix"),
-            GgaQualityIndicator::PpsFix => write!(f, "PPS fix"),
-            GgaQualityIndicator::RealTimeKinematic => write!(f, "Real-Time Kinematic"),
-            GgaQualityIndicator::RealTimeKinematicFloat => {
-                write!(f, "Real-Time Kinematic (floating point)")
-            }
-            GgaQualityIndicator::DeadReckoning => write!(f, "dead reckoning"),
-            GgaQualityIndicator::ManualInputMode => write!(f, "manual input mode"),
-            GgaQualityIndicator::SimulationMode => write!(f, "simulation mode"),
+            GgaQualityIndicator::Other(value) => write!(f, "{} ({})", self.description(), value),
+            _ => write!(f, "{}", self.description()),
         }
     }
 }
@@ -119,22 +166,42 @@ impl core::fmt::Display for GgaQualityIndicator {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    permissive_coordinates: bool,
+    keep_invalid_positions: bool,
 ) -> Result<ParsedMessage, ParseError> {
     let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
 
+    // Altitude and geoid separation are always expected in metres
+    for (index, name) in [(10, "altitude"), (12, "geoid separation")] {
+        let unit = split.get(index).unwrap_or(&"");
+        if !unit.is_empty() && *unit != "M" {
+            warn!("Unexpected GGA {} unit: {}", name, unit);
+        }
+    }
+
+    let quality_code: Option<u8> = pick_number_field(&split, 6)?;
+    let quality = GgaQualityIndicator::from_u8(quality_code.unwrap_or(0));
+
+    // Receivers may emit a stale position when there's no fix. Only an explicit "0" is
+    // considered as no fix; an empty quality field keeps the position.
+    let position_valid = keep_invalid_positions || quality_code != Some(0);
+
     Ok(ParsedMessage::Gga(GgaData {
         source: nav_system,
         timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
         latitude: parse_latitude_ddmm_mmm(
             split.get(2).unwrap_or(&""),
             split.get(3).unwrap_or(&""),
-        )?,
+            permissive_coordinates,
+        )?
+        .filter(|_| position_valid),
         longitude: parse_longitude_dddmm_mmm(
             split.get(4).unwrap_or(&""),
             split.get(5).unwrap_or(&""),
-        )?,
-        quality: GgaQualityIndicator::new(pick_number_field(&split, 6)?.unwrap_or(0)),
+        )?
+        .filter(|_| position_valid),
+        quality,
         satellite_count: pick_number_field(&split, 7)?,
         hdop: pick_number_field(&split, 8)?,
         altitude: pick_number_field(&split, 9)?,
@@ -248,4 +315,235 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_gga_geoid_separation() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        ) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert::close(gga.altitude.unwrap_or(0.0), 61.7, 0.01);
+                assert::close(gga.geoid_separation.unwrap_or(0.0), 55.2, 0.01);
+                assert::close(gga.ellipsoidal_height().unwrap_or(0.0), 116.9, 0.01);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Unexpected altitude unit is parsed anyway
+        match p.parse_sentence(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,F,-34.2,M,,*57",
+        ) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert::close(gga.altitude.unwrap_or(0.0), 61.7, 0.01);
+                assert::close(gga.geoid_separation.unwrap_or(0.0), -34.2, 0.01);
+                assert::close(gga.ellipsoidal_height().unwrap_or(0.0), 27.5, 0.01);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gga_fractional_seconds() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGGA,123519.25,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*6E")
+        {
+            Ok(ParsedMessage::Gga(gga)) => {
+                let timestamp = gga.timestamp.unwrap();
+                assert_eq!(timestamp.second(), 19);
+                assert_eq!(timestamp.nanosecond(), 250_000_000);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gga_permissive_coordinates() {
+        let sentence = "$GPGGA,123519,12030.00,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+
+        // Longitude in latitude's position is an error by default
+        let mut p = NmeaParser::new();
+        assert!(p.parse_sentence(sentence).is_err());
+
+        p.set_permissive_coordinates(true);
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(gga.latitude, None);
+                assert::close(gga.longitude.unwrap_or(0.0), 11.517, 0.001);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Latitude beyond the pole
+        match p.parse_sentence("$GPGGA,123519,9530.00,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*78") {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(gga.latitude, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gga_trailing_vendor_fields() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,,ABC,7.5*2B",
+        ) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(gga.timestamp.map(|t| t.second()), Some(19));
+                assert::close(gga.latitude.unwrap_or(0.0), 48.117, 0.001);
+                assert::close(gga.longitude.unwrap_or(0.0), 11.517, 0.001);
+                assert_eq!(gga.quality, GgaQualityIndicator::GpsFix);
+                assert_eq!(gga.satellite_count, Some(8));
+                assert_eq!(gga.hdop, Some(0.9));
+                assert_eq!(gga.altitude, Some(545.4));
+                assert_eq!(gga.geoid_separation, Some(46.9));
+                assert_eq!(gga.age_of_dgps, None);
+                assert_eq!(gga.ref_station_id, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gga_rtk_quality() {
+        let mut p = NmeaParser::new();
+        for (sentence, quality, description) in [
+            (
+                "$GNGGA,123519,4807.038,N,01131.000,E,4,12,0.6,545.4,M,46.9,M,1.0,0031*75",
+                GgaQualityIndicator::RealTimeKinematic,
+                "Real-Time Kinematic",
+            ),
+            (
+                "$GNGGA,123519,4807.038,N,01131.000,E,5,12,0.6,545.4,M,46.9,M,1.0,0031*74",
+                GgaQualityIndicator::RealTimeKinematicFloat,
+                "Real-Time Kinematic (floating point)",
+            ),
+            (
+                "$GNGGA,123519,4807.038,N,01131.000,E,9,12,0.6,545.4,M,46.9,M,1.0,0031*78",
+                GgaQualityIndicator::Other(9),
+                "unknown",
+            ),
+        ] {
+            match p.parse_sentence(sentence) {
+                Ok(ParsedMessage::Gga(gga)) => {
+                    assert_eq!(gga.quality, quality);
+                    assert_eq!(gga.quality.description(), description);
+                    assert_eq!(GgaQualityIndicator::from_u8(gga.quality.to_value()), quality);
+                }
+                Ok(_) => {
+                    assert!(false);
+                }
+                Err(e) => {
+                    assert_eq!(e.to_string(), "OK");
+                }
+            }
+        }
+        assert_eq!(GgaQualityIndicator::Other(9).to_value(), 9);
+        assert_eq!(GgaQualityIndicator::new(9), GgaQualityIndicator::Other(9));
+        assert_eq!(GgaQualityIndicator::Other(9).to_string(), "unknown (9)");
+        assert_eq!(GgaQualityIndicator::RealTimeKinematic.to_string(), "Real-Time Kinematic");
+    }
+
+    #[test]
+    fn test_parse_gga_invalid_fix_stale_position() {
+        let sentence = "$GPGGA,123519,4807.038,N,01131.000,E,0,00,,545.4,M,46.9,M,,*69";
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(gga.quality, GgaQualityIndicator::Invalid);
+                assert_eq!(gga.latitude, None);
+                assert_eq!(gga.longitude, None);
+                assert_eq!(gga.altitude, Some(545.4));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        p.set_keep_invalid_gga_positions(true);
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert::close(gga.latitude.unwrap_or(0.0), 48.117, 0.001);
+                assert::close(gga.longitude.unwrap_or(0.0), 11.517, 0.001);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gga_empty_quality_keeps_position() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,,08,0.9,545.4,M,46.9,M,,*76") {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(gga.quality, GgaQualityIndicator::Invalid);
+                assert::close(gga.latitude.unwrap_or(0.0), 48.117, 0.001);
+                assert::close(gga.longitude.unwrap_or(0.0), 11.517, 0.001);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gga_timed() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47") {
+            Ok(ParsedMessage::Gga(gga)) => {
+                // Only the time of day is decoded
+                assert_eq!(
+                    Timed::timestamp(&gga),
+                    Utc.with_ymd_and_hms(2000, 1, 1, 12, 35, 19).single()
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
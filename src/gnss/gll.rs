@@ -16,7 +16,8 @@ limitations under the License.
 use super::*;
 
 /// GLL - geographic Position - Latitude/Longitude
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GllData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -27,15 +28,15 @@ pub struct GllData {
     /// Longitude in degrees.
     pub longitude: Option<f64>,
 
-    /// UTC of position fix
-    #[serde(with = "json_date_time_utc")]
+    /// UTC of position fix. Date is set to 2000-01-01.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// True = data valid, false = data invalid.
     pub data_valid: Option<bool>,
 
-    /// FAA mode indicator (NMEA 2.3 and later).
-    pub faa_mode: Option<FaaMode>,
+    /// Positioning mode indicator (NMEA 2.3 and later).
+    pub positioning_mode: Option<PositioningMode>,
 }
 
 impl LatLon for GllData {
@@ -48,12 +49,21 @@ impl LatLon for GllData {
     }
 }
 
+/// Only the time of day is meaningful because GLL doesn't carry a date. The date is set to
+/// 2000-01-01.
+impl Timed for GllData {
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// xxGLL: Geographic Position, Latitude / Longitude and time.
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    permissive_coordinates: bool,
 ) -> Result<ParsedMessage, ParseError> {
     let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
@@ -63,6 +73,7 @@ pub(crate) fn handle(
         latitude: parse_latitude_ddmm_mmm(
             split.get(1).unwrap_or(&""),
             split.get(2).unwrap_or(&""),
+            permissive_coordinates,
         )?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(3).unwrap_or(&""),
@@ -76,7 +87,7 @@ pub(crate) fn handle(
                 _ => None,
             }
         },
-        faa_mode: FaaMode::new(split.get(7).unwrap_or(&"")).ok(),
+        positioning_mode: PositioningMode::new(split.get(7).unwrap_or(&"")),
     }))
 }
 
@@ -101,7 +112,7 @@ mod test {
                             Utc.with_ymd_and_hms(2000, 01, 01, 22, 54, 44).single()
                         });
                         assert_eq!(gll.data_valid, Some(true));
-                        assert_eq!(gll.faa_mode, Some(FaaMode::Differential));
+                        assert_eq!(gll.positioning_mode, Some(PositioningMode::Differential));
                     }
                     _ => {
                         assert!(false);
@@ -113,4 +124,51 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_gll_positioning_mode() {
+        let cases = [
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,A*5C", PositioningMode::Autonomous),
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,D*59", PositioningMode::Differential),
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,E*58", PositioningMode::Estimated),
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,M*50", PositioningMode::Manual),
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,S*4E", PositioningMode::Simulator),
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,N*53", PositioningMode::NotValid),
+            ("$GPGLL,4916.45,N,12311.12,W,225444,A,X*45", PositioningMode::Other('X')),
+        ];
+        let mut p = NmeaParser::new();
+        for (sentence, mode) in cases.iter() {
+            match p.parse_sentence(sentence) {
+                Ok(ParsedMessage::Gll(gll)) => {
+                    assert_eq!(gll.positioning_mode, Some(*mode));
+                }
+                Ok(_) => {
+                    assert!(false);
+                }
+                Err(e) => {
+                    assert_eq!(e.to_string(), "OK");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gll_timed() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GAGLL,4916.45,N,12311.12,W,225444,A,D*48") {
+            Ok(ParsedMessage::Gll(gll)) => {
+                // Only the time of day is decoded
+                assert_eq!(
+                    Timed::timestamp(&gll),
+                    Utc.with_ymd_and_hms(2000, 1, 1, 22, 54, 44).single()
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -18,11 +18,13 @@ use super::*;
 
 /// GNS - GNSS fix data
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GnsData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// UTC of position fix
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Latitude in degrees
@@ -52,11 +54,26 @@ pub struct GnsData {
     /// Height of geoid (mean sea level) above WGS84 ellipsoid
     pub geoid_separation: Option<f64>,
 
-    /// Age of differential GPS data record, Type 1 or Type 9.
+    /// Age of differential GPS data record, Type 1 or Type 9. `None` if the sentence omits it.
     pub age_of_dgps: Option<f64>,
 
-    /// Reference station ID, range 0000-4095
+    /// Differential reference station ID, range 0000-4095. `None` if the sentence omits it.
     pub ref_station_id: Option<u16>,
+
+    /// Positioning mode of each navigation system in the order of the mode indicator string
+    /// (GPS, GLONASS, Galileo, BeiDou, QZSS, NavIC)
+    pub positioning_modes: Vec<PositioningMode>,
+
+    /// Navigational status indicator (NMEA 4.10 and later): `S` = safe, `C` = caution,
+    /// `U` = unsafe, `V` = not valid
+    pub nav_status: Option<char>,
+}
+
+impl GnsData {
+    /// Return the positioning mode of the first navigation system.
+    pub fn positioning_mode(&self) -> Option<PositioningMode> {
+        self.positioning_modes.first().copied()
+    }
 }
 
 impl LatLon for GnsData {
@@ -71,6 +88,7 @@ impl LatLon for GnsData {
 
 /// GNS mode indicator
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum GnsModeIndicator {
     /// Satellite system not used in position fix, or fix not valid
     Invalid,
@@ -137,17 +155,19 @@ impl core::fmt::Display for GnsModeIndicator {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
     let modes: Vec<char> = split.get(6).unwrap_or(&"").chars().collect();
 
-    Ok(ParsedMessage::Gns(GnsData {
+    let gns = GnsData {
         source: nav_system,
         timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
         latitude: parse_latitude_ddmm_mmm(
             split.get(2).unwrap_or(&""),
             split.get(3).unwrap_or(&""),
+            store.permissive_coordinates,
         )?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(4).unwrap_or(&""),
@@ -166,7 +186,20 @@ pub(crate) fn handle(
         geoid_separation: pick_number_field(&split, 10)?,
         age_of_dgps: pick_number_field(&split, 11)?,
         ref_station_id: pick_number_field(&split, 12)?,
-    }))
+        positioning_modes: split
+            .get(6)
+            .unwrap_or(&"")
+            .chars()
+            .map(PositioningMode::from)
+            .collect(),
+        nav_status: split.get(13).and_then(|s| s.chars().next()),
+    };
+
+    if store.gns_accumulation {
+        store.push_gns(gns.clone());
+    }
+
+    Ok(ParsedMessage::Gns(gns))
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -194,12 +227,14 @@ mod test {
                         assert_eq!(gns.gps_mode, GnsModeIndicator::Autonomous);
                         assert_eq!(gns.glonass_mode, GnsModeIndicator::Autonomous);
                         assert_eq!(gns.other_modes[0], GnsModeIndicator::Invalid);
+                        assert_eq!(gns.positioning_mode(), Some(PositioningMode::Autonomous));
                         assert_eq!(gns.satellite_count.unwrap_or(0), 10);
                         assert::close(gns.hdop.unwrap_or(0.0), 0.9, 0.1);
                         assert::close(gns.altitude.unwrap_or(0.0), 532.4, 0.1);
                         assert::close(gns.geoid_separation.unwrap_or(0.0), 47.0, 0.1);
                         assert_eq!(gns.age_of_dgps, None);
                         assert_eq!(gns.ref_station_id, None);
+                        assert_eq!(gns.nav_status, Some('V'));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -249,4 +284,98 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_gns_positioning_modes() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GNGNS,090310.00,4806.891632,N,01134.134167,E,ADNN,14,0.8,532.4,47.0,,,S*2B",
+        ) {
+            Ok(ParsedMessage::Gns(gns)) => {
+                assert_eq!(
+                    gns.positioning_modes,
+                    vec![
+                        PositioningMode::Autonomous,
+                        PositioningMode::Differential,
+                        PositioningMode::NotValid,
+                        PositioningMode::NotValid,
+                    ]
+                );
+                assert_eq!(gns.nav_status, Some('S'));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gns_differential_fields() {
+        let mut p = NmeaParser::new();
+
+        // NMEA 4.10 with navigational status
+        match p.parse_sentence(
+            "$GNGNS,090310.00,4806.891632,N,01134.134167,E,DDNN,14,0.8,532.4,47.0,2.5,0123,S*07",
+        ) {
+            Ok(ParsedMessage::Gns(gns)) => {
+                assert::close(gns.latitude.unwrap_or(0.0), 48.115, 0.001);
+                assert_eq!(gns.satellite_count, Some(14));
+                assert_eq!(gns.age_of_dgps, Some(2.5));
+                assert_eq!(gns.ref_station_id, Some(123));
+                assert_eq!(gns.nav_status, Some('S'));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Older form without navigational status
+        match p.parse_sentence(
+            "$GPGNS,090310.00,4806.891632,N,01134.134167,E,DN,08,1.2,532.4,47.0,3.0,0042*68",
+        ) {
+            Ok(ParsedMessage::Gns(gns)) => {
+                assert::close(gns.latitude.unwrap_or(0.0), 48.115, 0.001);
+                assert_eq!(gns.satellite_count, Some(8));
+                assert_eq!(gns.age_of_dgps, Some(3.0));
+                assert_eq!(gns.ref_station_id, Some(42));
+                assert_eq!(gns.nav_status, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gns_accumulation() {
+        let mut p = NmeaParser::new();
+        p.set_gns_accumulation(true);
+        assert!(p.combined_gns().is_empty());
+
+        p.parse_sentence("$GPGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,,*57")
+            .ok();
+        p.parse_sentence("$GLGNS,112257.00,3844.24011,N,00908.43828,W,NA,05,2.1,,,,*7A")
+            .ok();
+        let sources: Vec<NavigationSystem> =
+            p.combined_gns().iter().map(|gns| gns.source).collect();
+        assert_eq!(sources, vec![NavigationSystem::Gps, NavigationSystem::Glonass]);
+
+        // A new fix replaces the earlier one
+        p.parse_sentence("$GPGNS,112258.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,,*58")
+            .ok();
+        assert_eq!(p.combined_gns().len(), 1);
+        assert_eq!(p.combined_gns()[0].source, NavigationSystem::Gps);
+
+        p.set_gns_accumulation(false);
+        assert!(p.combined_gns().is_empty());
+    }
 }
@@ -15,7 +15,8 @@ limitations under the License.
 */
 use super::*;
 /// GSA - GNSS dilution of position (DOP) and active satellites
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GsaData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -37,10 +38,15 @@ pub struct GsaData {
 
     /// Vertical dilution of precision
     pub vdop: Option<f64>,
+
+    /// GNSS system ID (NMEA 4.10 and later): 1 = GPS, 2 = GLONASS, 3 = Galileo, 4 = BeiDou,
+    /// 5 = QZSS, 6 = NavIC
+    pub system_id: Option<u8>,
 }
 
 /// GSA position fix type
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum GsaFixMode {
     /// No fix.
     NotAvailable,
@@ -68,10 +74,11 @@ impl core::fmt::Display for GsaFixMode {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
-    Ok(ParsedMessage::Gsa(GsaData {
+    let gsa = GsaData {
         source: nav_system,
         mode1_automatic: {
             let s = split.get(1).unwrap_or(&"");
@@ -110,7 +117,61 @@ pub(crate) fn handle(
         pdop: pick_number_field(&split, 15)?,
         hdop: pick_number_field(&split, 16)?,
         vdop: pick_number_field(&split, 17)?,
-    }))
+        system_id: pick_hex_field(&split, 18)?,
+    };
+
+    if store.gsa_accumulation {
+        store.push_gsa(gsa.clone());
+    }
+
+    Ok(ParsedMessage::Gsa(gsa))
+}
+
+/// Maximum number of GSA sentences of unidentified constellations kept in store.
+pub(crate) const MAX_UNIDENTIFIED_GSAS: usize = 6;
+
+/// Return the key used to identify the constellation of the given GSA in store. System ID is
+/// preferred and the talker ID is used with sentences which don't have one. `None` is returned
+/// when neither identifies a single constellation (e.g. GN talker without system ID).
+pub(crate) fn constellation_key(gsa: &GsaData) -> Option<u8> {
+    if let Some(system_id) = gsa.system_id {
+        return Some(system_id);
+    }
+    match gsa.source {
+        NavigationSystem::Gps => Some(1),
+        NavigationSystem::Glonass => Some(2),
+        NavigationSystem::Galileo => Some(3),
+        NavigationSystem::Beidou => Some(4),
+        NavigationSystem::Qzss => Some(5),
+        NavigationSystem::Navic => Some(6),
+        _ => None,
+    }
+}
+
+/// Merge per-constellation GSA sentences into one. PRN numbers are concatenated, fix mode is the
+/// best one available and the rest of the fields are taken from the most recent sentence.
+pub(crate) fn merge(gsas: &[GsaData]) -> Option<GsaData> {
+    let latest = gsas.last()?;
+    Some(GsaData {
+        source: NavigationSystem::Combination,
+        mode1_automatic: latest.mode1_automatic,
+        mode2_3d: gsas
+            .iter()
+            .filter_map(|gsa| gsa.mode2_3d)
+            .max_by_key(|mode| match mode {
+                GsaFixMode::NotAvailable => 0,
+                GsaFixMode::Fix2D => 1,
+                GsaFixMode::Fix3D => 2,
+            }),
+        prn_numbers: gsas
+            .iter()
+            .flat_map(|gsa| gsa.prn_numbers.iter().copied())
+            .collect(),
+        pdop: latest.pdop,
+        hdop: latest.hdop,
+        vdop: latest.vdop,
+        system_id: None,
+    })
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -133,6 +194,7 @@ mod test {
                         assert_eq!(gsa.pdop, Some(1.7));
                         assert_eq!(gsa.hdop, Some(1.0));
                         assert_eq!(gsa.vdop, Some(1.3));
+                        assert_eq!(gsa.system_id, None);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -147,4 +209,80 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_gngsa_with_system_id() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsa(gsa) => {
+                    assert_eq!(gsa.source, NavigationSystem::Combination);
+                    assert_eq!(gsa.mode1_automatic, Some(true));
+                    assert_eq!(gsa.mode2_3d, Some(GsaFixMode::Fix3D));
+                    assert_eq!(gsa.prn_numbers, vec![80, 71, 73, 79, 69]);
+                    assert_eq!(gsa.pdop, Some(1.83));
+                    assert_eq!(gsa.hdop, Some(1.09));
+                    assert_eq!(gsa.vdop, Some(1.47));
+                    assert_eq!(gsa.system_id, Some(2));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gsa_accumulation() {
+        let mut p = NmeaParser::new();
+        p.set_gsa_accumulation(true);
+        assert_eq!(p.combined_gsa(), None);
+
+        p.parse_sentence("$GNGSA,A,3,10,16,18,20,26,27,,,,,,,1.83,1.09,1.47,1*0D")
+            .ok();
+        p.parse_sentence("$GNGSA,A,2,80,71,73,,,,,,,,,,1.80,1.05,1.45,2*04")
+            .ok();
+        // Replaces the earlier GPS sentence
+        p.parse_sentence("$GNGSA,A,3,10,16,18,,,,,,,,,,1.83,1.09,1.47,1*0E")
+            .ok();
+
+        let gsa = p.combined_gsa().unwrap();
+        assert_eq!(gsa.source, NavigationSystem::Combination);
+        assert_eq!(gsa.mode2_3d, Some(GsaFixMode::Fix3D));
+        assert_eq!(gsa.prn_numbers, vec![80, 71, 73, 10, 16, 18]);
+        assert_eq!(gsa.hdop, Some(1.09));
+
+        p.reset();
+        assert_eq!(p.combined_gsa(), None);
+    }
+
+    #[test]
+    fn test_gsa_accumulation_without_system_id() {
+        let mut p = NmeaParser::new();
+        p.set_gsa_accumulation(true);
+
+        p.parse_sentence("$GNGSA,A,3,10,16,18,,,,,,,,,,1.83,1.09,1.47*13")
+            .ok();
+        p.parse_sentence("$GNGSA,A,3,80,71,73,,,,,,,,,,1.83,1.09,1.47*16")
+            .ok();
+        let gsa = p.combined_gsa().unwrap();
+        assert_eq!(gsa.prn_numbers, vec![10, 16, 18, 80, 71, 73]);
+
+        // Repeated satellite starts a new fix
+        p.parse_sentence("$GNGSA,A,3,10,16,,,,,,,,,,,1.83,1.09,1.47*1A")
+            .ok();
+        let gsa = p.combined_gsa().unwrap();
+        assert_eq!(gsa.prn_numbers, vec![10, 16]);
+    }
+
+    #[test]
+    fn test_gsa_accumulation_disabled() {
+        let mut p = NmeaParser::new();
+        p.parse_sentence("$GPGSA,A,3,19,28,14,18,27,22,31,39,,,,,1.7,1.0,1.3*34")
+            .ok();
+        assert_eq!(p.combined_gsa(), None);
+    }
 }
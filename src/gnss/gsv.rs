@@ -16,7 +16,8 @@ limitations under the License.
 use super::*;
 
 /// GSV - satellite information
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GsvData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -24,14 +25,54 @@ pub struct GsvData {
     /// Satellite PRN number
     pub prn_number: u8,
 
-    /// Elevation in degrees (max 90°)
+    /// Elevation in degrees (max 90°), None when empty or out of range
     pub elevation: Option<f32>,
 
-    /// Azimuth in degrees from True north (0°-359°)
+    /// Azimuth in degrees from True north (0°-359°), None when empty or out of range
     pub azimuth: Option<f32>,
 
-    /// SNR, 0-99 dB, None when not tracking
+    /// SNR, 0-99 dB, None when not tracking or out of range
     pub snr: Option<f32>,
+
+    /// Total number of satellites in view declared by the GSV sentence
+    pub total_in_view: u8,
+}
+
+impl GsvData {
+    /// Return the satellite information with integer values.
+    pub fn satellite_info(&self) -> SatelliteInfo {
+        SatelliteInfo {
+            prn: u16::from(self.prn_number),
+            elevation: self.elevation.map(|v| v.round() as u8),
+            azimuth: self.azimuth.map(|v| v.round() as u16 % 360),
+            snr: self.snr.map(|v| v.round() as u8),
+        }
+    }
+
+    /// Return true if the number of satellites enumerated in the given complete GSV burst
+    /// matches the declared total number of satellites in view.
+    pub fn is_consistent(burst: &[GsvData]) -> bool {
+        burst
+            .iter()
+            .all(|gsv| gsv.total_in_view as usize == burst.len())
+    }
+}
+
+/// Information of a single satellite in view
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SatelliteInfo {
+    /// Satellite PRN number
+    pub prn: u16,
+
+    /// Elevation in degrees (0-90)
+    pub elevation: Option<u8>,
+
+    /// Azimuth in degrees from True north (0-359)
+    pub azimuth: Option<u16>,
+
+    /// SNR in dB (0-99), None when not tracking
+    pub snr: Option<u8>,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -61,23 +102,19 @@ pub(crate) fn handle(
         for i in 1..(msg_count + 1) {
             if let Some(sentence) = store.pull_string(make_gsv_key(msg_type, msg_count, i)) {
                 let split: Vec<&str> = sentence.split(',').collect();
+                let total_in_view = pick_number_field(&split, 3).ok().flatten().unwrap_or(0);
                 for j in 0..4 {
-                    if let Some(prn) = pick_number_field(&split, 4 + 4 * j as usize)
+                    if let Some(prn) = pick_number_field(&split, 4 + 4 * j)
                         .ok()
                         .unwrap_or(None)
                     {
                         v.push(GsvData {
                             source: nav_system,
                             prn_number: prn,
-                            elevation: pick_number_field(&split, 4 + 4 * j as usize + 1)
-                                .ok()
-                                .unwrap_or(None),
-                            azimuth: pick_number_field(&split, 4 + 4 * j as usize + 2)
-                                .ok()
-                                .unwrap_or(None),
-                            snr: pick_number_field(&split, 4 + 4 * j as usize + 3)
-                                .ok()
-                                .unwrap_or(None),
+                            elevation: pick_ranged_field(&split, 4 + 4 * j + 1, 0.0, 90.0),
+                            azimuth: pick_ranged_field(&split, 4 + 4 * j + 2, 0.0, 359.0),
+                            snr: pick_ranged_field(&split, 4 + 4 * j + 3, 0.0, 99.0),
+                            total_in_view,
                         });
                     }
                 }
@@ -86,10 +123,22 @@ pub(crate) fn handle(
 
         Ok(ParsedMessage::Gsv(v))
     } else {
+        store.set_fragment_progress(
+            found_count.min(255) as u8,
+            msg_count.min(255) as u8,
+        );
         Ok(ParsedMessage::Incomplete)
     }
 }
 
+/// Pick a numeric field and return `None` if it's empty, invalid or out of the given range.
+fn pick_ranged_field(split: &[&str], num: usize, min: f32, max: f32) -> Option<f32> {
+    pick_number_field(split, num)
+        .ok()
+        .flatten()
+        .filter(|v: &f32| *v >= min && *v <= max)
+}
+
 /// Make key for store
 fn make_gsv_key(sentence_type: &str, msg_count: u32, msg_num: u32) -> String {
     format!("{},{},{}", sentence_type, msg_count, msg_num)
@@ -145,6 +194,8 @@ mod test {
                     // The expected result
                     ParsedMessage::Gsv(v) => {
                         assert_eq!(v.len(), 11);
+                        assert_eq!(v[0].total_in_view, 11);
+                        assert!(GsvData::is_consistent(&v));
 
                         // 2nd satellite
                         let s2 = v.get(1).unwrap();
@@ -176,4 +227,71 @@ mod test {
         }
         assert_eq!(p.strings_count(), 0);
     }
+
+    #[test]
+    fn test_parse_gpgsv_empty_and_invalid_fields() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("$GPGSV,2,1,07,02,35,291,,03,09,129,,05,14,305,,06,38,226,47*7D"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("$GPGSV,2,2,07,12,95,360,120,25,,,,29,12,045,31*4B") {
+            Ok(ParsedMessage::Gsv(v)) => {
+                let prns: Vec<u8> = v.iter().map(|s| s.prn_number).collect();
+                assert_eq!(prns, vec![2, 3, 5, 6, 12, 25, 29]);
+
+                // Tracked but not used
+                assert_eq!(
+                    v[0].satellite_info(),
+                    SatelliteInfo {
+                        prn: 2,
+                        elevation: Some(35),
+                        azimuth: Some(291),
+                        snr: None,
+                    }
+                );
+                assert_eq!(v[3].satellite_info().snr, Some(47));
+
+                // Out of range values
+                assert_eq!(
+                    v[4].satellite_info(),
+                    SatelliteInfo {
+                        prn: 12,
+                        elevation: None,
+                        azimuth: None,
+                        snr: None,
+                    }
+                );
+
+                // PRN only
+                assert_eq!(v[5].elevation, None);
+                assert_eq!(v[5].azimuth, None);
+                assert_eq!(v[5].snr, None);
+
+                assert_eq!(v[6].satellite_info().azimuth, Some(45));
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_gsv_inconsistent_total() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("$GPGSV,2,1,09,02,35,291,,03,09,129,,05,14,305,,06,38,226,47*73"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("$GPGSV,2,2,09,12,45,160,30,25,20,080,28,29,12,045,31*4A") {
+            Ok(ParsedMessage::Gsv(v)) => {
+                assert_eq!(v.len(), 7);
+                assert_eq!(v[6].total_in_view, 9);
+                assert!(!GsvData::is_consistent(&v));
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
 }
@@ -17,8 +17,12 @@ limitations under the License.
 use super::*;
 
 /// HDT - Heading, true
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HdtData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
     /// Heading - true
     pub heading_true: Option<f64>,
 }
@@ -26,10 +30,14 @@ pub struct HdtData {
 // -------------------------------------------------------------------------------------------------
 
 /// xxHDT: Heading, true
-pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Hdt(HdtData {
+        source: nav_system,
         heading_true: pick_number_field(&split, 1)?,
     }))
 }
@@ -0,0 +1,91 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// HSC - Heading steering command
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HscData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Commanded heading, degrees true
+    pub heading_true: Option<f64>,
+
+    /// Commanded heading, degrees magnetic
+    pub heading_magnetic: Option<f64>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxHSC: Heading steering command
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let mut hsc = HscData {
+        source: nav_system,
+        heading_true: None,
+        heading_magnetic: None,
+    };
+
+    parse_unit_fields(&split, 1..5, |unit, value| match unit {
+        "T" => hsc.heading_true = value,
+        "M" => hsc.heading_magnetic = value,
+        _ => {}
+    })?;
+
+    Ok(ParsedMessage::Hsc(hsc))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_hsc() {
+        match NmeaParser::new().parse_sentence("$APHSC,123.4,T,118.9,M*55") {
+            Ok(ParsedMessage::Hsc(hsc)) => {
+                assert_eq!(hsc.heading_true, Some(123.4));
+                assert_eq!(hsc.heading_magnetic, Some(118.9));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Values in reversed order and an empty value
+        match NmeaParser::new().parse_sentence("$APHSC,,M,123.4,T*7A") {
+            Ok(ParsedMessage::Hsc(hsc)) => {
+                assert_eq!(hsc.heading_true, Some(123.4));
+                assert_eq!(hsc.heading_magnetic, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
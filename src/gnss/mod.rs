@@ -35,14 +35,29 @@ pub(crate) mod mtw;
 pub(crate) mod vhw;
 pub(crate) mod hdt;
 pub(crate) mod mwv;
+pub(crate) mod vdr;
+pub(crate) mod bod;
+pub(crate) mod aam;
+pub(crate) mod dbk;
+pub(crate) mod rsa;
+pub(crate) mod vpw;
+pub(crate) mod dsc;
+pub(crate) mod fix_assembler;
+pub(crate) mod hsc;
+pub(crate) mod ztg;
+pub(crate) mod zfo;
+pub(crate) mod osd;
+pub(crate) mod alr;
+pub(crate) mod ack;
 
 use super::*;
 pub use gga::{GgaData, GgaQualityIndicator};
 pub use gll::GllData;
 pub use gns::GnsData;
 pub use gsa::{GsaData, GsaFixMode};
-pub use gsv::GsvData;
+pub use gsv::{GsvData, SatelliteInfo};
 pub use rmc::RmcData;
+#[cfg(feature = "serde")]
 use serde::Serialize;
 pub use vtg::VtgData;
 pub use alm::AlmData;
@@ -57,11 +72,26 @@ pub use mtw::MtwData;
 pub use vhw::VhwData;
 pub use hdt::HdtData;
 pub use mwv::MwvData;
+pub use vdr::VdrData;
+pub use bod::BodData;
+pub use aam::AamData;
+pub use dbk::DbkData;
+pub use rsa::RsaData;
+pub use vpw::VpwData;
+pub use dsc::DscData;
+pub use fix_assembler::{FixAssembler, GnssFix};
+pub use hsc::HscData;
+pub use ztg::ZtgData;
+pub use zfo::ZfoData;
+pub use osd::OsdData;
+pub use alr::AlrData;
+pub use ack::AckData;
 
 // -------------------------------------------------------------------------------------------------
 
 /// Navigation system, identified with NMEA GNSS sentence prefix (e.g. $BDGGA)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum NavigationSystem {
     /// Combination of several satellite systems
     Combination, // GNxxx
@@ -139,45 +169,77 @@ impl core::str::FromStr for NavigationSystem {
 }
 
 // -------------------------------------------------------------------------------------------------
-/// VTG/GLL FAA mode (NMEA 2.3 standard has this information)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
-pub enum FaaMode {
-    /// Autonomous mode (automatic 2D/3D)
+/// Positioning mode indicator of GLL, RMC, VTG and GNS sentences (NMEA 2.3 and later)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum PositioningMode {
+    /// Autonomous mode (A)
     Autonomous,
 
-    /// Differential GPS mode (DGPS).
+    /// Differential mode (D)
     Differential,
 
-    /// Estimated (dead-reckoning) data.
+    /// Estimated (dead-reckoning) mode (E)
     Estimated,
 
-    /// No valid data available.
-    NotValid,
+    /// Manual input mode (M)
+    Manual,
 
-    /// Simulated data.
+    /// Simulator mode (S)
     Simulator,
+
+    /// Data not valid (N)
+    NotValid,
+
+    /// Unrecognized mode indicator
+    Other(char),
+}
+
+impl PositioningMode {
+    /// Decode mode indicator field. Returns `None` if the field is empty.
+    pub fn new(val: &str) -> Option<PositioningMode> {
+        val.chars().next().map(PositioningMode::from)
+    }
+}
+
+impl From<char> for PositioningMode {
+    fn from(c: char) -> Self {
+        match c {
+            'A' => PositioningMode::Autonomous,
+            'D' => PositioningMode::Differential,
+            'E' => PositioningMode::Estimated,
+            'M' => PositioningMode::Manual,
+            'S' => PositioningMode::Simulator,
+            'N' => PositioningMode::NotValid,
+            _ => PositioningMode::Other(c),
+        }
+    }
 }
 
-impl FaaMode {
-    pub fn new(val: &str) -> Result<FaaMode, String> {
-        match val {
-            "A" => Ok(FaaMode::Autonomous),
-            "D" => Ok(FaaMode::Differential),
-            "E" => Ok(FaaMode::Estimated),
-            "N" => Ok(FaaMode::NotValid),
-            _ => Err(format!("Unrecognized FAA information value: {}", val)),
+impl From<PositioningMode> for char {
+    fn from(mode: PositioningMode) -> Self {
+        match mode {
+            PositioningMode::Autonomous => 'A',
+            PositioningMode::Differential => 'D',
+            PositioningMode::Estimated => 'E',
+            PositioningMode::Manual => 'M',
+            PositioningMode::Simulator => 'S',
+            PositioningMode::NotValid => 'N',
+            PositioningMode::Other(c) => c,
         }
     }
 }
 
-impl core::fmt::Display for FaaMode {
+impl core::fmt::Display for PositioningMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            FaaMode::Autonomous => write!(f, "A"),
-            FaaMode::Differential => write!(f, "D"),
-            FaaMode::Estimated => write!(f, "E"),
-            FaaMode::NotValid => write!(f, "N"),
-            _ => write!(f, "?"),
+            PositioningMode::Autonomous => write!(f, "autonomous"),
+            PositioningMode::Differential => write!(f, "differential"),
+            PositioningMode::Estimated => write!(f, "estimated"),
+            PositioningMode::Manual => write!(f, "manual input"),
+            PositioningMode::Simulator => write!(f, "simulator"),
+            PositioningMode::NotValid => write!(f, "not valid"),
+            PositioningMode::Other(c) => write!(f, "other ({})", c),
         }
     }
 }
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// MSS - Multiple Data ID
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MssData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -17,8 +17,12 @@ limitations under the License.
 use super::*;
 
 /// MTW - Mean Temperature of Water
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MtwData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
     /// Water temperature in degrees Celsius
     pub temperature: Option<f64>,
 }
@@ -26,10 +30,14 @@ pub struct MtwData {
 // -------------------------------------------------------------------------------------------------
 
 /// xxMTW: Mean Temperature of Water
-pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Mtw(MtwData {
+        source: nav_system,
         temperature: pick_number_field(&split, 1)?,
     }))
 }
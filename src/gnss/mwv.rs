@@ -17,8 +17,12 @@ limitations under the License.
 use super::*;
 
 /// MWV - Wind speed and angle
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MwvData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
     /// wind angle, 0 to 359 degrees
     pub wind_angle: Option<f64>,
 
@@ -36,10 +40,14 @@ pub struct MwvData {
 
 /// xxMWV: Wind speed and angle
 
-pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Mwv(MwvData {
+        source: nav_system,
         wind_angle: pick_number_field(&split, 1)?,
         relative: match pick_string_field(&split, 2)
             .ok_or(ParseError::CorruptedSentence(
@@ -0,0 +1,126 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// OSD - Own ship data
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OsdData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Heading, degrees true
+    pub heading_true: Option<f64>,
+
+    /// Heading status: true = data valid, false = data invalid
+    pub heading_valid: Option<bool>,
+
+    /// Vessel course, degrees true
+    pub course_true: Option<f64>,
+
+    /// Course reference (`B` = bottom tracking log, `M` = manually entered, `W` = water
+    /// referenced, `R` = radar tracking of fixed target, `P` = positioning system ground
+    /// reference)
+    pub course_reference: Option<char>,
+
+    /// Vessel speed in `speed_units`
+    pub speed: Option<f64>,
+
+    /// Speed reference, same characters as in `course_reference`
+    pub speed_reference: Option<char>,
+
+    /// Vessel set, degrees true
+    pub set_true: Option<f64>,
+
+    /// Vessel drift (speed) in `speed_units`
+    pub drift: Option<f64>,
+
+    /// Unit of speed and drift (`K` = km/h, `N` = knots, `S` = statute miles/h)
+    pub speed_units: Option<char>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxOSD: Own ship data
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Osd(OsdData {
+        source: nav_system,
+        heading_true: pick_number_field(&split, 1)?,
+        heading_valid: parse_status_flag(&split, 2, "OSD heading status")?,
+        course_true: pick_number_field(&split, 3)?,
+        course_reference: split.get(4).and_then(|s| s.chars().next()),
+        speed: pick_number_field(&split, 5)?,
+        speed_reference: split.get(6).and_then(|s| s.chars().next()),
+        set_true: pick_number_field(&split, 7)?,
+        drift: pick_number_field(&split, 8)?,
+        speed_units: split.get(9).and_then(|s| s.chars().next()),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_osd() {
+        match NmeaParser::new().parse_sentence("$RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.1,N*41") {
+            Ok(ParsedMessage::Osd(osd)) => {
+                assert_eq!(osd.heading_true, Some(35.1));
+                assert_eq!(osd.heading_valid, Some(true));
+                assert_eq!(osd.course_true, Some(36.0));
+                assert_eq!(osd.course_reference, Some('P'));
+                assert_eq!(osd.speed, Some(10.2));
+                assert_eq!(osd.speed_reference, Some('P'));
+                assert_eq!(osd.set_true, Some(15.3));
+                assert_eq!(osd.drift, Some(0.1));
+                assert_eq!(osd.speed_units, Some('N'));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_osd_empty_fields() {
+        match NmeaParser::new().parse_sentence("$RAOSD,,V,,,,,,,*31") {
+            Ok(ParsedMessage::Osd(osd)) => {
+                assert_eq!(osd.heading_true, None);
+                assert_eq!(osd.heading_valid, Some(false));
+                assert_eq!(osd.course_reference, None);
+                assert_eq!(osd.speed, None);
+                assert_eq!(osd.speed_units, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -16,13 +16,14 @@ limitations under the License.
 use super::*;
 
 /// RMC - position, velocity, and time (Recommended Minimum sentence C)
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct RmcData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// Fix datetime based on HHMMSS and DDMMYY
-    #[serde(with = "json_date_time_utc")]
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Status: true = active, false = void.
@@ -42,6 +43,13 @@ pub struct RmcData {
 
     /// Magnetic variation in degrees
     pub variation: Option<f64>,
+
+    /// Positioning mode indicator (NMEA 2.3 and later)
+    pub positioning_mode: Option<PositioningMode>,
+
+    /// Navigational status indicator (NMEA 4.10 and later): `S` = safe, `C` = caution,
+    /// `U` = unsafe, `V` = not valid
+    pub nav_status: Option<char>,
 }
 
 impl LatLon for RmcData {
@@ -54,12 +62,19 @@ impl LatLon for RmcData {
     }
 }
 
+impl Timed for RmcData {
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// xxRMC: Recommended minimum specific GPS/Transit data
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    permissive_coordinates: bool,
 ) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
@@ -81,6 +96,7 @@ pub(crate) fn handle(
         latitude: parse_latitude_ddmm_mmm(
             split.get(3).unwrap_or(&""),
             split.get(4).unwrap_or(&""),
+            permissive_coordinates,
         )?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(5).unwrap_or(&""),
@@ -102,9 +118,52 @@ pub(crate) fn handle(
                 None
             }
         },
+        positioning_mode: PositioningMode::new(split.get(12).unwrap_or(&"")),
+        nav_status: split.get(13).and_then(|s| s.chars().next()),
     }))
 }
 
+/// Encode the given RMC data as a sentence with the given talker ID, e.g. "GP".
+pub(crate) fn encode(rmc: &RmcData, talker: &str) -> String {
+    let optional = |val: Option<f64>| val.map(|v| format!("{}", v)).unwrap_or_default();
+    let mut sentence = format!(
+        "${}RMC,{},{},{},{},{},{},{},{},{}",
+        talker,
+        rmc.timestamp.as_ref().map(format_nmea_time).unwrap_or_default(),
+        match rmc.status_active {
+            Some(true) => "A",
+            Some(false) => "V",
+            None => "",
+        },
+        rmc.latitude
+            .map(format_latitude_ddmm_mmmm)
+            .unwrap_or_else(|| ",".into()),
+        rmc.longitude
+            .map(format_longitude_dddmm_mmmm)
+            .unwrap_or_else(|| ",".into()),
+        optional(rmc.sog_knots),
+        optional(rmc.bearing),
+        rmc.timestamp.as_ref().map(format_nmea_date).unwrap_or_default(),
+        optional(rmc.variation.map(num_traits::Float::abs)),
+        match rmc.variation {
+            Some(v) if v < 0.0 => "W",
+            Some(_) => "E",
+            None => "",
+        },
+    );
+    if rmc.positioning_mode.is_some() || rmc.nav_status.is_some() {
+        sentence.push(',');
+        if let Some(mode) = rmc.positioning_mode {
+            sentence.push(char::from(mode));
+        }
+    }
+    if let Some(nav_status) = rmc.nav_status {
+        sentence.push(',');
+        sentence.push(nav_status);
+    }
+    append_checksum(&sentence)
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -128,6 +187,7 @@ mod test {
                         assert_eq!(rmc.sog_knots.unwrap(), 0.5);
                         assert::close(rmc.bearing.unwrap_or(0.0), 54.7, 0.1);
                         assert_eq!(rmc.variation.unwrap(), 20.3);
+                        assert_eq!(rmc.positioning_mode, None);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -170,4 +230,128 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_rmc_positioning_mode() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E,D*0F",
+        ) {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(rmc.variation.unwrap(), 20.3);
+                assert_eq!(rmc.positioning_mode, Some(PositioningMode::Differential));
+                assert_eq!(
+                    Timed::timestamp(&rmc),
+                    Utc.with_ymd_and_hms(2020, 11, 19, 22, 54, 46).single()
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rmc_fractional_seconds() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPRMC,225446.5,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*7C")
+        {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(rmc.timestamp, {
+                    Utc.with_ymd_and_hms(2020, 11, 19, 22, 54, 46)
+                        .single()
+                        .map(|t| t + chrono::Duration::milliseconds(500))
+                });
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rmc_nav_status() {
+        let mut p = NmeaParser::new();
+
+        // NMEA 4.10 with navigational status
+        match p.parse_sentence(
+            "$GNRMC,120000.00,A,6010.1234,N,02456.5678,E,5.2,78.4,161026,,,A,S*08",
+        ) {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert::close(rmc.latitude.unwrap_or(0.0), 60.168723, 0.000001);
+                assert::close(rmc.longitude.unwrap_or(0.0), 24.942797, 0.000001);
+                assert_eq!(rmc.sog_knots, Some(5.2));
+                assert_eq!(rmc.positioning_mode, Some(PositioningMode::Autonomous));
+                assert_eq!(rmc.nav_status, Some('S'));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Pre-4.10 without navigational status
+        match p.parse_sentence("$GPRMC,120000.00,A,6010.1234,N,02456.5678,E,5.2,78.4,161026,,,A*69")
+        {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert::close(rmc.latitude.unwrap_or(0.0), 60.168723, 0.000001);
+                assert::close(rmc.longitude.unwrap_or(0.0), 24.942797, 0.000001);
+                assert_eq!(rmc.sog_knots, Some(5.2));
+                assert_eq!(rmc.positioning_mode, Some(PositioningMode::Autonomous));
+                assert_eq!(rmc.nav_status, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rmc_round_trip() {
+        let mut p = NmeaParser::new();
+        let original = match p.parse_sentence(
+            "$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*67",
+        ) {
+            Ok(msg) => msg,
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+                return;
+            }
+        };
+        let sentence = original.to_sentence("GN").unwrap();
+        assert_eq!(
+            sentence,
+            "$GNRMC,225446.00,A,4916.4500,N,12311.1200,W,0.5,54.7,191120,20.3,E*57"
+        );
+        match p.parse_sentence(&sentence) {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(rmc.source, NavigationSystem::Combination);
+                assert_eq!(
+                    ParsedMessage::Rmc(RmcData {
+                        source: NavigationSystem::Gps,
+                        ..rmc
+                    }),
+                    original
+                );
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(original.to_sentence("G"), None);
+    }
 }
@@ -0,0 +1,101 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// RSA - Rudder Sensor Angle
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RsaData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Starboard (or single) rudder angle in degrees, negative values mean port
+    pub starboard_angle: Option<f64>,
+
+    /// Starboard (or single) rudder sensor status: true = valid, false = invalid
+    pub starboard_status: Option<bool>,
+
+    /// Port rudder angle in degrees, negative values mean port
+    pub port_angle: Option<f64>,
+
+    /// Port rudder sensor status: true = valid, false = invalid
+    pub port_status: Option<bool>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxRSA: Rudder Sensor Angle
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Rsa(RsaData {
+        source: nav_system,
+        starboard_angle: pick_number_field(&split, 1)?,
+        starboard_status: parse_status_flag(&split, 2, "RSA sensor status")?,
+        port_angle: pick_number_field(&split, 3)?,
+        port_status: parse_status_flag(&split, 4, "RSA sensor status")?,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_rsa() {
+        match NmeaParser::new().parse_sentence("$IIRSA,10.5,A,-9.8,A*58") {
+            Ok(ps) => match ps {
+                ParsedMessage::Rsa(rsa) => {
+                    assert_eq!(rsa.starboard_angle, Some(10.5));
+                    assert_eq!(rsa.starboard_status, Some(true));
+                    assert_eq!(rsa.port_angle, Some(-9.8));
+                    assert_eq!(rsa.port_status, Some(true));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rsa_single_rudder() {
+        match NmeaParser::new().parse_sentence("$IIRSA,-3.2,A,,V*55") {
+            Ok(ps) => match ps {
+                ParsedMessage::Rsa(rsa) => {
+                    assert_eq!(rsa.starboard_angle, Some(-3.2));
+                    assert_eq!(rsa.starboard_status, Some(true));
+                    assert_eq!(rsa.port_angle, None);
+                    assert_eq!(rsa.port_status, Some(false));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// VBW - Dual Ground/Water Speed
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VbwData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -0,0 +1,105 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// VDR - Set and drift
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VdrData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Direction of the current, degrees true
+    pub direction_true: Option<f64>,
+
+    /// Direction of the current, degrees magnetic
+    pub direction_magnetic: Option<f64>,
+
+    /// Speed of the current, knots
+    pub speed_knots: Option<f64>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxVDR: Set and drift
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let mut vdr = VdrData {
+        source: nav_system,
+        direction_true: None,
+        direction_magnetic: None,
+        speed_knots: None,
+    };
+
+    parse_unit_fields(&split, 1..7, |unit, value| match unit {
+        "T" => vdr.direction_true = value,
+        "M" => vdr.direction_magnetic = value,
+        "N" => vdr.speed_knots = value,
+        _ => {}
+    })?;
+
+    Ok(ParsedMessage::Vdr(vdr))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vdr() {
+        match NmeaParser::new().parse_sentence("$IIVDR,10.1,T,12.3,M,1.2,N*3A") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vdr(vdr) => {
+                    assert_eq!(vdr.direction_true, Some(10.1));
+                    assert_eq!(vdr.direction_magnetic, Some(12.3));
+                    assert_eq!(vdr.speed_knots, Some(1.2));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdr_empty_fields() {
+        match NmeaParser::new().parse_sentence("$IIVDR,,T,,M,,N*17") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vdr(vdr) => {
+                    assert_eq!(vdr.direction_true, None);
+                    assert_eq!(vdr.direction_magnetic, None);
+                    assert_eq!(vdr.speed_knots, None);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
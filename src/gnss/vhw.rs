@@ -17,8 +17,12 @@ limitations under the License.
 use super::*;
 
 /// VHW - Water speed and heading
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VhwData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
     /// Heading - true
     pub heading_true: Option<f64>,
 
@@ -32,19 +36,43 @@ pub struct VhwData {
     pub speed_through_water_kmh: Option<f64>,
 }
 
+impl VhwData {
+    /// Return velocity relative to water in meters per second. Knots are preferred and km/h is
+    /// used if knots aren't available.
+    pub fn speed_mps(&self) -> Option<f64> {
+        self.speed_through_water_knots
+            .map(|knots| knots * 1852.0 / 3600.0)
+            .or_else(|| self.speed_through_water_kmh.map(|kmh| kmh / 3.6))
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 // xxVHW: Water speed and heading
 
-pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
-    Ok(ParsedMessage::Vhw(VhwData {
-        heading_true: pick_number_field(&split, 1)?,
-        heading_magnetic: pick_number_field(&split, 3)?,
-        speed_through_water_knots: pick_number_field(&split, 5)?,
-        speed_through_water_kmh: pick_number_field(&split, 7)?,
-    }))
+    let mut vhw = VhwData {
+        source: nav_system,
+        heading_true: None,
+        heading_magnetic: None,
+        speed_through_water_knots: None,
+        speed_through_water_kmh: None,
+    };
+
+    parse_unit_fields(&split, 1..9, |unit, value| match unit {
+        "T" => vhw.heading_true = value,
+        "M" => vhw.heading_magnetic = value,
+        "N" => vhw.speed_through_water_knots = value,
+        "K" => vhw.speed_through_water_kmh = value,
+        _ => {}
+    })?;
+
+    Ok(ParsedMessage::Vhw(vhw))
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -72,4 +100,54 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vhw_speed_only() {
+        match NmeaParser::new().parse_sentence("$VWVHW,,T,,M,5.5,N,10.2,K*67") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vhw(vhw) => {
+                    assert_eq!(vhw.heading_true, None);
+                    assert_eq!(vhw.heading_magnetic, None);
+                    assert_eq!(vhw.speed_through_water_knots, Some(5.5));
+                    assert_eq!(vhw.speed_through_water_kmh, Some(10.2));
+                    assert::close(vhw.speed_mps().unwrap_or(0.0), 2.829, 0.001);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vhw_trailing_vendor_fields() {
+        match NmeaParser::new().parse_sentence("$IIVHW,15.0,T,15.0,M,6.3,N,11.8,K,VENDOR,N*22") {
+            Ok(ParsedMessage::Vhw(vhw)) => {
+                assert_eq!(vhw.heading_true, Some(15.0));
+                assert_eq!(vhw.speed_through_water_knots, Some(6.3));
+                assert_eq!(vhw.speed_through_water_kmh, Some(11.8));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_speed_mps_from_kmh() {
+        let vhw = VhwData {
+            source: NavigationSystem::Other,
+            heading_true: None,
+            heading_magnetic: None,
+            speed_through_water_knots: None,
+            speed_through_water_kmh: Some(18.0),
+        };
+        assert::close(vhw.speed_mps().unwrap_or(0.0), 5.0, 0.001);
+    }
 }
@@ -0,0 +1,90 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// VPW - Speed Measured Parallel to Wind
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VpwData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Speed parallel to true wind in knots, negative values mean moving away from the wind
+    pub speed_knots: Option<f64>,
+
+    /// Speed parallel to true wind in meters per second, negative values mean moving away
+    /// from the wind
+    pub speed_mps: Option<f64>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxVPW: Speed Measured Parallel to Wind
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Vpw(VpwData {
+        source: nav_system,
+        speed_knots: pick_number_field(&split, 1)?,
+        speed_mps: pick_number_field(&split, 3)?,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vpw() {
+        match NmeaParser::new().parse_sentence("$IIVPW,-1.5,N,-0.77,M*66") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vpw(vpw) => {
+                    assert_eq!(vpw.speed_knots, Some(-1.5));
+                    assert_eq!(vpw.speed_mps, Some(-0.77));
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vpw_knots_only() {
+        match NmeaParser::new().parse_sentence("$IIVPW,4.2,N,,M*7A") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vpw(vpw) => {
+                    assert_eq!(vpw.speed_knots, Some(4.2));
+                    assert_eq!(vpw.speed_mps, None);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -16,7 +16,8 @@ limitations under the License.
 use super::*;
 
 /// VTG - track made good and speed over ground
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VtgData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -33,8 +34,9 @@ pub struct VtgData {
     /// Speed over ground (SoG), km/h
     pub sog_kph: Option<f64>,
 
-    /// FAA mode indicator
-    pub faa_mode: Option<FaaMode>,
+    /// Positioning mode indicator (NMEA 2.3 and later). The course and speed fields are
+    /// decoded also when the mode is `PositioningMode::NotValid`.
+    pub positioning_mode: Option<PositioningMode>,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -52,7 +54,7 @@ pub(crate) fn handle(
         cog_magnetic: pick_number_field(&split, 3).ok().unwrap_or(None),
         sog_knots: pick_number_field(&split, 5).ok().unwrap_or(None),
         sog_kph: pick_number_field(&split, 7).ok().unwrap_or(None),
-        faa_mode: FaaMode::new(split.get(9).unwrap_or(&"")).ok(),
+        positioning_mode: PositioningMode::new(split.get(9).unwrap_or(&"")),
     }))
 }
 
@@ -75,7 +77,7 @@ mod test {
                         assert::close(vtg.cog_magnetic.unwrap_or(0.0), 34.4, 0.1);
                         assert::close(vtg.sog_knots.unwrap_or(0.0), 5.5, 0.1);
                         assert::close(vtg.sog_kph.unwrap_or(0.0), 10.2, 0.1);
-                        assert_eq!(vtg.faa_mode, Some(FaaMode::Differential));
+                        assert_eq!(vtg.positioning_mode, Some(PositioningMode::Differential));
                     }
                     _ => {
                         assert!(false);
@@ -87,4 +89,40 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vtg_positioning_mode() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,E*21") {
+            Ok(ParsedMessage::Vtg(vtg)) => {
+                assert_eq!(vtg.positioning_mode, Some(PositioningMode::Estimated));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vtg_not_valid_mode() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,N*2A") {
+            Ok(ParsedMessage::Vtg(vtg)) => {
+                assert_eq!(vtg.cog_true, Some(54.7));
+                assert_eq!(vtg.cog_magnetic, Some(34.4));
+                assert_eq!(vtg.sog_knots, Some(5.5));
+                assert_eq!(vtg.sog_kph, Some(10.2));
+                assert_eq!(vtg.positioning_mode, Some(PositioningMode::NotValid));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
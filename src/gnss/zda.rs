@@ -17,20 +17,27 @@ limitations under the License.
 use super::*;
 
 /// ZDA - Time and date
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ZdaData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// UTC
-    #[serde(with = "json_date_time_utc")]
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp_utc: Option<DateTime<Utc>>,
 
     /// Local time zone offset
-    #[serde(with = "json_fixed_offset")]
+    #[cfg_attr(feature = "serde", serde(with = "json_fixed_offset"))]
     pub timezone_local: Option<FixedOffset>,
 }
 
+impl Timed for ZdaData {
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp_utc
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// xxZDA: MSK Receiver Signal
@@ -68,6 +75,7 @@ mod test {
                         Utc.with_ymd_and_hms(2018, 5, 31, 7, 29, 14).single()
                     );
                     assert_eq!(zda.timezone_local, FixedOffset::east_opt(-3 * 3600));
+                    assert_eq!(zda.timestamp(), zda.timestamp_utc);
                 }
                 ParsedMessage::Incomplete => {
                     assert!(false);
@@ -0,0 +1,82 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// ZFO - UTC and time from origin waypoint
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ZfoData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of the observation. Date is set to 2000-01-01.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Elapsed time from the origin waypoint in seconds
+    pub time_from_origin_seconds: Option<f64>,
+
+    /// Origin waypoint ID
+    pub waypoint_id: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxZFO: UTC and time from origin waypoint
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Zfo(ZfoData {
+        source: nav_system,
+        timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
+        time_from_origin_seconds: parse_hhmmss_duration(split.get(2).unwrap_or(&""))?,
+        waypoint_id: pick_string_field(&split, 3),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_zfo() {
+        match NmeaParser::new().parse_sentence("$GPZFO,145832.12,001502,ORIGIN*5E") {
+            Ok(ParsedMessage::Zfo(zfo)) => {
+                assert_eq!(zfo.source, NavigationSystem::Gps);
+                assert_eq!(zfo.timestamp.map(|t| t.minute()), Some(58));
+                assert_eq!(zfo.time_from_origin_seconds, Some(902.0));
+                assert_eq!(zfo.waypoint_id, Some("ORIGIN".to_string()));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Invalid duration
+        assert!(NmeaParser::new()
+            .parse_sentence("$GPZFO,145832.12,0075,ORIGIN*5A")
+            .is_err());
+    }
+}
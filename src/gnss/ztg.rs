@@ -0,0 +1,96 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// ZTG - UTC and time to destination waypoint
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ZtgData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of the observation. Date is set to 2000-01-01.
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Time to go to the destination waypoint in seconds
+    pub time_to_go_seconds: Option<f64>,
+
+    /// Destination waypoint ID
+    pub waypoint_id: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxZTG: UTC and time to destination waypoint
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Ztg(ZtgData {
+        source: nav_system,
+        timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
+        time_to_go_seconds: parse_hhmmss_duration(split.get(2).unwrap_or(&""))?,
+        waypoint_id: pick_string_field(&split, 3),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_ztg() {
+        match NmeaParser::new().parse_sentence("$GPZTG,145832.12,042359.17,WPT*24") {
+            Ok(ParsedMessage::Ztg(ztg)) => {
+                assert_eq!(ztg.source, NavigationSystem::Gps);
+                assert_eq!(
+                    ztg.timestamp,
+                    Utc.with_ymd_and_hms(2000, 1, 1, 14, 58, 32)
+                        .single()
+                        .map(|t| t + chrono::Duration::milliseconds(120))
+                );
+                assert::close(ztg.time_to_go_seconds.unwrap_or(0.0), 15839.17, 0.001);
+                assert_eq!(ztg.waypoint_id, Some("WPT".to_string()));
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Empty fields
+        match NmeaParser::new().parse_sentence("$GPZTG,145832.12,,*56") {
+            Ok(ParsedMessage::Ztg(ztg)) => {
+                assert_eq!(ztg.time_to_go_seconds, None);
+                assert_eq!(ztg.waypoint_id, None);
+            }
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
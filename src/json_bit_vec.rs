@@ -0,0 +1,9 @@
+use bitvec::prelude::*;
+use alloc::string::String;
+use serde::Serializer;
+
+/// Serialize the bits as a string of '0' and '1' characters, most significant bit first.
+pub fn serialize<S: Serializer>(bits: &BitVec, serializer: S) -> Result<S::Ok, S::Error> {
+    let s: String = bits.iter().map(|b| if *b { '1' } else { '0' }).collect();
+    serializer.serialize_str(&s)
+}
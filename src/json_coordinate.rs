@@ -0,0 +1,18 @@
+use num_traits::Float;
+use serde::{Serialize, Serializer};
+
+/// Number of decimals of latitudes and longitudes in JSON output, about 0.1 m
+pub const DEFAULT_DECIMALS: u32 = 6;
+
+pub fn serialize<S: Serializer>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => round(*v, DEFAULT_DECIMALS).serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Round the given value to the given number of decimals.
+pub fn round(value: f64, decimals: u32) -> f64 {
+    let factor = Float::powi(10.0, decimals as i32);
+    Float::round(value * factor) / factor
+}
@@ -1,12 +1,16 @@
 //! JSON serialization structures for NMEA messages
 //! This module provides JSON-serializable equivalents of the main NMEA message types
 
-use crate::ParsedMessage;
+use crate::{NmeaMessage, OwnedNmeaMessage, ParsedMessage};
+use crate::json_coordinate;
 use crate::tag_block::TagBlock;
 use serde::{Deserialize, Serialize};
 use alloc::string::{String, ToString};
 use alloc::format;
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+use chrono::{Datelike, Timelike};
+use serde_json::Value;
 
 /// Augmentation information for modified/enhanced data
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -25,14 +29,26 @@ pub struct JsonNmeaMessage {
     pub augmentations: Option<Vec<Augmentation>>,
 }
 
+/// Estimated time of arrival of a vessel (UTC)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonEta {
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
 /// Serializable version of ParsedMessage for JSON output
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data")]
 pub enum JsonParsedMessage {
     // AIS Messages (simplified for JSON)
     VesselDynamicData {
+        own_vessel: bool,
         mmsi: u32,
+        #[serde(serialize_with = "json_coordinate::serialize")]
         latitude: Option<f64>,
+        #[serde(serialize_with = "json_coordinate::serialize")]
         longitude: Option<f64>,
         speed_over_ground: Option<f64>,
         course_over_ground: Option<f64>,
@@ -41,16 +57,25 @@ pub enum JsonParsedMessage {
         message_type: u8,
     },
     VesselStaticData {
+        own_vessel: bool,
         mmsi: u32,
         vessel_name: Option<String>,
         call_sign: Option<String>,
         vessel_type: Option<u8>,
         dimensions: Option<String>,
+        imo_number: Option<u32>,
+        draught: Option<f64>,
+        destination: Option<String>,
+        eta: Option<JsonEta>,
+        epfd_type: Option<u8>,
         message_type: u8,
     },
     BaseStationReport {
+        own_vessel: bool,
         mmsi: u32,
+        #[serde(serialize_with = "json_coordinate::serialize")]
         latitude: Option<f64>,
+        #[serde(serialize_with = "json_coordinate::serialize")]
         longitude: Option<f64>,
         timestamp: Option<i64>,
         message_type: u8,
@@ -65,7 +90,9 @@ pub enum JsonParsedMessage {
     },
     // GNSS Messages (simplified for JSON)
     Gga {
+        #[serde(serialize_with = "json_coordinate::serialize")]
         latitude: Option<f64>,
+        #[serde(serialize_with = "json_coordinate::serialize")]
         longitude: Option<f64>,
         fix_quality: Option<u8>,
         num_satellites: Option<u8>,
@@ -74,7 +101,9 @@ pub enum JsonParsedMessage {
         timestamp: Option<i64>,
     },
     Rmc {
+        #[serde(serialize_with = "json_coordinate::serialize")]
         latitude: Option<f64>,
+        #[serde(serialize_with = "json_coordinate::serialize")]
         longitude: Option<f64>,
         speed: Option<f64>,
         course: Option<f64>,
@@ -99,6 +128,29 @@ impl JsonNmeaMessage {
         }
     }
     
+    /// Create JSON message from a message returned by `NmeaParser::parse_sentence_owned`.
+    pub fn from_owned(owned: OwnedNmeaMessage) -> Self {
+        JsonNmeaMessage::new(owned.parsed.message, owned.parsed.tag_block, owned.raw)
+    }
+
+    /// Round latitudes and longitudes of the message to the given number of decimals.
+    /// Coordinates are always serialized with at most six decimals (about 0.1 m), so this is
+    /// useful only for coarser output.
+    pub fn with_coordinate_precision(mut self, decimals: u32) -> Self {
+        match &mut self.message {
+            JsonParsedMessage::VesselDynamicData { latitude, longitude, .. }
+            | JsonParsedMessage::BaseStationReport { latitude, longitude, .. }
+            | JsonParsedMessage::Gga { latitude, longitude, .. }
+            | JsonParsedMessage::Rmc { latitude, longitude, .. } => {
+                for v in [latitude, longitude] {
+                    *v = v.map(|v| json_coordinate::round(v, decimals));
+                }
+            }
+            _ => {}
+        }
+        self
+    }
+
     pub fn with_augmentations(mut self, augmentations: Vec<Augmentation>) -> Self {
         self.augmentations = Some(augmentations);
         self
@@ -109,6 +161,7 @@ impl From<ParsedMessage> for JsonParsedMessage {
     fn from(msg: ParsedMessage) -> Self {
         match msg {
             ParsedMessage::VesselDynamicData(vdd) => JsonParsedMessage::VesselDynamicData {
+                own_vessel: vdd.own_vessel,
                 mmsi: vdd.mmsi,
                 latitude: vdd.latitude,
                 longitude: vdd.longitude,
@@ -116,9 +169,10 @@ impl From<ParsedMessage> for JsonParsedMessage {
                 course_over_ground: vdd.cog,
                 true_heading: vdd.heading_true.map(|h| h as u16),
                 timestamp: Some(vdd.timestamp_seconds),
-                message_type: 1, // Type 1/2/3 dynamic data
+                message_type: vdd.message_type,
             },
             ParsedMessage::VesselStaticData(vsd) => JsonParsedMessage::VesselStaticData {
+                own_vessel: vsd.own_vessel,
                 mmsi: vsd.mmsi,
                 vessel_name: vsd.name.clone(),
                 call_sign: vsd.call_sign.clone(),
@@ -131,9 +185,20 @@ impl From<ParsedMessage> for JsonParsedMessage {
                         None
                     }
                 },
-                message_type: 5, // Type 5 static data
+                imo_number: vsd.imo_number,
+                draught: vsd.draught10.map(|d| d as f64 / 10.0),
+                destination: vsd.destination.clone(),
+                eta: vsd.eta.map(|eta| JsonEta {
+                    month: eta.month(),
+                    day: eta.day(),
+                    hour: eta.hour(),
+                    minute: eta.minute(),
+                }),
+                epfd_type: vsd.position_fix_type.map(|t| t.to_value()),
+                message_type: vsd.message_type,
             },
             ParsedMessage::BaseStationReport(bsr) => JsonParsedMessage::BaseStationReport {
+                own_vessel: bsr.own_vessel,
                 mmsi: bsr.mmsi,
                 latitude: bsr.latitude,
                 longitude: bsr.longitude,
@@ -159,7 +224,7 @@ impl From<ParsedMessage> for JsonParsedMessage {
             ParsedMessage::Gga(gga) => JsonParsedMessage::Gga {
                 latitude: gga.latitude,
                 longitude: gga.longitude,
-                fix_quality: Some(gga.quality as u8),
+                fix_quality: Some(gga.quality.to_value()),
                 num_satellites: gga.satellite_count,
                 hdop: gga.hdop,
                 altitude: gga.altitude,
@@ -210,6 +275,21 @@ impl From<ParsedMessage> for JsonParsedMessage {
                     ParsedMessage::Vhw(_) => "Vhw",
                     ParsedMessage::Vtg(_) => "Vtg",
                     ParsedMessage::Zda(_) => "Zda",
+                    ParsedMessage::Vdr(_) => "Vdr",
+                    ParsedMessage::Bod(_) => "Bod",
+                    ParsedMessage::Aam(_) => "Aam",
+                    ParsedMessage::Dbk(_) => "Dbk",
+                    ParsedMessage::Filtered(_) => "Filtered",
+                    ParsedMessage::Duplicate => "Duplicate",
+                    ParsedMessage::Rsa(_) => "Rsa",
+                    ParsedMessage::Vpw(_) => "Vpw",
+                    ParsedMessage::Dsc(_) => "Dsc",
+                    ParsedMessage::Hsc(_) => "Hsc",
+                    ParsedMessage::Ztg(_) => "Ztg",
+                    ParsedMessage::Zfo(_) => "Zfo",
+                    ParsedMessage::Osd(_) => "Osd",
+                    ParsedMessage::Alr(_) => "Alr",
+                    ParsedMessage::Ack(_) => "Ack",
                     _ => "Unknown",
                 };
                 JsonParsedMessage::Unknown {
@@ -221,6 +301,76 @@ impl From<ParsedMessage> for JsonParsedMessage {
     }
 }
 
+/// Column names of the records returned by `to_csv_record`.
+pub fn csv_header() -> Vec<String> {
+    ["timestamp", "mmsi", "lat", "lon", "sog", "cog", "heading", "nav_status"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Convert an AIS position report to a CSV record with the columns returned by `csv_header`.
+/// Timestamp is the tag block UNIX time, if any. Unavailable values are empty strings and
+/// navigation status is the numeric code. Returns `None` for non-position messages.
+pub fn to_csv_record(msg: &NmeaMessage) -> Option<Vec<String>> {
+    fn opt<T: ToString>(v: Option<T>) -> String {
+        v.map(|v| v.to_string()).unwrap_or_default()
+    }
+
+    match &msg.message {
+        ParsedMessage::VesselDynamicData(vdd) => Some(vec![
+            opt(msg.tag_block.as_ref().and_then(|tb| tb.timestamp)),
+            vdd.mmsi.to_string(),
+            opt(vdd.latitude),
+            opt(vdd.longitude),
+            opt(vdd.sog_knots),
+            opt(vdd.cog),
+            opt(vdd.heading_true),
+            vdd.nav_status.to_value().to_string(),
+        ]),
+        _ => None,
+    }
+}
+
+/// Flatten all populated fields of the message to a map keyed by field name, e.g. "mmsi" or
+/// "sog_knots". Nested fields are joined with a dot ("dimension.to_bow") and list items are
+/// keyed by their index. Unavailable values are left out. A message which is a bare value, such
+/// as the type of a filtered AIS message, is stored under "value".
+pub fn to_flat_map(msg: &ParsedMessage) -> BTreeMap<String, Value> {
+    fn flatten(prefix: &str, value: Value, map: &mut BTreeMap<String, Value>) {
+        let key = |k: &str| {
+            if prefix.is_empty() {
+                k.to_string()
+            } else {
+                format!("{}.{}", prefix, k)
+            }
+        };
+        match value {
+            Value::Null => {}
+            Value::Object(fields) => {
+                for (k, v) in fields {
+                    flatten(&key(&k), v, map);
+                }
+            }
+            Value::Array(items) => {
+                for (i, v) in items.into_iter().enumerate() {
+                    flatten(&key(&i.to_string()), v, map);
+                }
+            }
+            v => {
+                let key = if prefix.is_empty() { "value" } else { prefix };
+                map.insert(key.to_string(), v);
+            }
+        }
+    }
+
+    let mut map = BTreeMap::new();
+    if let Ok(value) = serde_json::to_value(msg) {
+        flatten("", value, &mut map);
+    }
+    map
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +406,139 @@ mod tests {
         assert!(json_str.contains("VesselDynamicData"));
         assert!(json_str.contains("12345"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_json_own_vessel() {
+        let mut p = crate::NmeaParser::new();
+        let msg = p
+            .parse_sentence("!AIVDO,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*48")
+            .unwrap();
+        let json_msg = JsonNmeaMessage::new(msg, None, "test sentence".to_string());
+        match json_msg.message {
+            JsonParsedMessage::VesselDynamicData { own_vessel, .. } => assert!(own_vessel),
+            _ => panic!("Expected VesselDynamicData"),
+        }
+        let json_str = serde_json::to_string(&json_msg).unwrap();
+        assert!(json_str.contains("\"own_vessel\":true"));
+
+        let msg = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        let json_msg = JsonNmeaMessage::new(msg, None, "test sentence".to_string());
+        let json_str = serde_json::to_string(&json_msg).unwrap();
+        assert!(json_str.contains("\"own_vessel\":false"));
+    }
+
+    #[test]
+    fn test_json_vessel_static_data() {
+        let mut p = crate::NmeaParser::new();
+        p.parse_sentence(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        )
+        .unwrap();
+        let msg = p.parse_sentence("!AIVDM,2,2,1,A,88888888880,2*25").unwrap();
+        let json_msg = JsonNmeaMessage::new(msg, None, "test sentence".to_string());
+        let json_str = serde_json::to_string(&json_msg).unwrap();
+        assert!(json_str.contains("\"imo_number\":9134270"));
+        assert!(json_str.contains("\"draught\":12.2"));
+        assert!(json_str.contains("\"destination\":\"NEW YORK\""));
+        assert!(json_str.contains("\"eta\":{\"month\":5,\"day\":15,\"hour\":14,\"minute\":0}"));
+        assert!(json_str.contains("\"epfd_type\":1"));
+
+        // Fields not included in the message are null
+        p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D")
+            .unwrap();
+        let msg = p
+            .parse_sentence("!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40")
+            .unwrap();
+        let json_msg = JsonNmeaMessage::new(msg, None, "test sentence".to_string());
+        let json_str = serde_json::to_string(&json_msg).unwrap();
+        assert!(json_str.contains("\"imo_number\":null"));
+        assert!(json_str.contains("\"draught\":null"));
+        assert!(json_str.contains("\"destination\":null"));
+        assert!(json_str.contains("\"eta\":null"));
+        assert!(json_str.contains("\"epfd_type\":null"));
+        let _: JsonNmeaMessage = serde_json::from_str(&json_str).unwrap();
+    }
+
+    #[test]
+    fn test_json_coordinate_precision() {
+        let mut p = crate::NmeaParser::new();
+        let msg = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        let json_msg = JsonNmeaMessage::new(msg, None, "test sentence".to_string());
+        let json_str = serde_json::to_string(&json_msg).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        for field in ["latitude", "longitude"] {
+            let s = value["message"]["data"][field].to_string();
+            let decimals = s.split('.').nth(1).map(|d| d.len()).unwrap_or(0);
+            assert!(decimals <= 6, "{} has too many decimals: {}", field, s);
+        }
+        assert!(json_str.contains("\"latitude\":48.381633"));
+
+        let json_str = serde_json::to_string(&json_msg.with_coordinate_precision(3)).unwrap();
+        assert!(json_str.contains("\"latitude\":48.382"));
+        assert!(json_str.contains("\"longitude\":-123.395"));
+    }
+
+    #[test]
+    fn test_csv_record() {
+        use crate::ais::{NavigationStatus, VesselDynamicData};
+
+        let vdd = VesselDynamicData {
+            mmsi: 12345,
+            nav_status: NavigationStatus::AtAnchor,
+            latitude: Some(37.7749),
+            longitude: Some(-122.4194),
+            sog_knots: Some(10.5),
+            cog: None,
+            heading_true: Some(50.0),
+            ..Default::default()
+        };
+        let mut tag_block = TagBlock::new();
+        tag_block.timestamp = Some(1577836800);
+        let msg = NmeaMessage::new(ParsedMessage::VesselDynamicData(vdd), Some(tag_block));
+
+        let record = to_csv_record(&msg).unwrap();
+        assert_eq!(record.len(), csv_header().len());
+        assert_eq!(
+            record,
+            vec!["1577836800", "12345", "37.7749", "-122.4194", "10.5", "", "50", "1"]
+        );
+
+        let msg = NmeaMessage::new(ParsedMessage::Incomplete, None);
+        assert_eq!(to_csv_record(&msg), None);
+    }
+
+    #[test]
+    fn test_flat_map() {
+        let mut p = crate::NmeaParser::new();
+        let vdd = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        let rmc = p
+            .parse_sentence("$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*67")
+            .unwrap();
+        let vdd_map = to_flat_map(&vdd);
+        let rmc_map = to_flat_map(&rmc);
+
+        // Common fields use the same keys
+        for key in ["latitude", "longitude", "sog_knots"] {
+            assert!(vdd_map.contains_key(key), "{} missing", key);
+            assert!(rmc_map.contains_key(key), "{} missing", key);
+        }
+        assert_eq!(vdd_map["mmsi"], Value::from(371798000));
+        assert_eq!(rmc_map["sog_knots"], Value::from(0.5));
+        assert_eq!(rmc_map["source"], Value::from("Gps"));
+        assert_eq!(rmc_map["timestamp"], Value::from("2020-11-19T22:54:46+00:00"));
+
+        // Type specific fields and unavailable values are left out
+        assert!(!rmc_map.contains_key("mmsi"));
+        assert!(!rmc_map.contains_key("positioning_mode"));
+        assert!(!vdd_map.contains_key("variation"));
+
+        assert!(to_flat_map(&ParsedMessage::Incomplete).is_empty());
+        assert_eq!(to_flat_map(&ParsedMessage::Filtered(5))["value"], Value::from(5));
+    }
+}
@@ -34,9 +34,10 @@ limitations under the License.
 //! - `d` - Destination identifier (max 15 chars)
 //! - `g` - Sentence grouping (format: sentence-total-group_id)
 //! - `n` - Line count
-//! - `r` - Relative time
+//! - `r` - Relative time (may be negative)
 //! - `s` - Source/station identifier
-//! - `t`/`i` - Text string (max 15 chars)
+//! - `t` - Text string (max 15 chars)
+//! - `i` - Sentence originator/identifier (max 15 chars)
 //!
 //! Use `parse_sentence_with_tags()` to access tag block information, or continue using
 //! `parse_sentence()` for backward compatibility (tag blocks are ignored).
@@ -55,28 +56,48 @@ extern crate num_traits;
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
 use alloc::string::{String, ToString};
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use bitvec::prelude::*;
 pub use chrono;
 use chrono::prelude::*;
 use chrono::{DateTime, TimeZone};
 use hashbrown::HashMap;
-use core::cmp::max;
 use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 
 
 pub mod ais;
 mod error;
 pub mod gnss;
+#[cfg(feature = "serde")]
 pub mod json_output;
+pub mod line_framer;
+#[cfg(feature = "std")]
+pub mod sentence_sink;
 pub mod tag_block;
 mod util;
+#[cfg(feature = "serde")]
 mod json_date_time_utc;
+#[cfg(feature = "serde")]
 mod json_fixed_offset;
+#[cfg(feature = "serde")]
+mod json_coordinate;
+#[cfg(feature = "serde")]
+mod json_bit_vec;
 
 pub use error::ParseError;
+pub use line_framer::LineFramer;
+#[cfg(feature = "std")]
+pub use sentence_sink::SentenceSink;
 pub use tag_block::TagBlock;
 use util::*;
 
@@ -89,31 +110,184 @@ pub struct NmeaMessage {
     pub message: ParsedMessage,
     /// Associated tag block if present
     pub tag_block: Option<TagBlock>,
+    /// Source sentences (without tag blocks) which produced the message
+    original_sentences: Vec<String>,
+    /// Complete AIS message bits if enabled with `NmeaParser::keep_raw_bits`
+    raw_bits: Option<RawBits>,
+    /// Number of fill bits declared by the last AIS sentence of the message
+    fill_bits: Option<u8>,
+}
+
+/// Parsed NMEA message together with the sentence it was parsed from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedNmeaMessage {
+    /// The input sentence without surrounding whitespace and line terminators
+    pub raw: String,
+    /// The parsed message
+    pub parsed: NmeaMessage,
+}
+
+/// Buffered fragment of an AIS multipart message which hasn't been completed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncompleteFragment {
+    /// Sentence type without the talker ID, e.g. `!VDM`
+    pub sentence_type: String,
+    /// Sequential message ID of the multipart message
+    pub message_id: u64,
+    /// Radio channel code, empty if not given
+    pub channel: String,
+    /// Fragment number (1-based)
+    pub fragment_number: u8,
+    /// Armored payload of the fragment
+    pub payload: String,
+    /// Original sentence of the fragment
+    pub sentence: Option<String>,
+}
+
+/// Complete bits of a decoded AIS message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawBits {
+    /// Message bits packed into bytes in big-endian bit order. The last byte is padded with
+    /// zero bits.
+    pub data: Vec<u8>,
+    /// Number of bits in the message
+    pub bit_length: usize,
 }
 
 impl NmeaMessage {
-    /// Create a new NMEA message with optional tag block
+    /// Create a new NMEA message with optional tag block. The source sentences, raw bits and fill
+    /// bits are not recorded, so `original_sentences` returns an empty slice and `talker`,
+    /// `raw_bits` and `fill_bits` return `None`.
     pub fn new(message: ParsedMessage, tag_block: Option<TagBlock>) -> Self {
-        NmeaMessage { message, tag_block }
+        NmeaMessage {
+            message,
+            tag_block,
+            original_sentences: Vec::new(),
+            raw_bits: None,
+            fill_bits: None,
+        }
     }
     
-    /// Create a new NMEA message without tag block
+    /// Create a new NMEA message without tag block. As with `new`, the source sentences are not
+    /// recorded.
     pub fn without_tag_block(message: ParsedMessage) -> Self {
         NmeaMessage { 
             message, 
-            tag_block: None 
+            tag_block: None,
+            original_sentences: Vec::new(),
+            raw_bits: None,
+            fill_bits: None,
+        }
+    }
+
+    /// Return the source sentences which produced the message, in fragment order. Single
+    /// sentence messages return a one-element slice. Tag blocks are not included. Messages
+    /// created with `new` or `without_tag_block` return an empty slice.
+    pub fn original_sentences(&self) -> &[String] {
+        &self.original_sentences
+    }
+
+    /// Return the two-character talker ID of the sentence which produced the message, such as
+    /// `GP`, `II` or `AI`. Multipart messages return the talker ID of the first fragment.
+    /// `None` is returned for proprietary sentences and when the source sentence isn't known.
+    pub fn talker(&self) -> Option<&str> {
+        let sentence = self.original_sentences.first()?;
+        let start = sentence.find(['$', '!'])?;
+        let talker = sentence.get((start + 1)..(start + 3))?;
+        if talker.starts_with('P') || !talker.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            None
+        } else {
+            Some(talker)
+        }
+    }
+
+    /// Return the best available absolute time of the message. Sources in order of precedence:
+    ///
+    /// 1. Tag block `c` field (UNIX time in seconds or milliseconds)
+    /// 2. Second of minute in AIS report (types 1-3, 9, 18, 21 and 27) snapped to the
+    ///    closest matching time around `reference`, normally the reception time
+    ///
+    /// `None` is returned if neither is available.
+    pub fn best_timestamp(&self, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if let Some(time) = self.tag_block_time() {
+            return Some(time);
+        }
+
+        let second = match &self.message {
+            ParsedMessage::VesselDynamicData(vdd) => vdd.timestamp_seconds,
+            ParsedMessage::StandardSarAircraftPositionReport(sar) => sar.timestamp_seconds,
+            ParsedMessage::AidToNavigationReport(aton) => aton.timestamp_seconds,
+            _ => return None,
+        };
+        if second > 59 {
+            return None;
+        }
+
+        // Pick the time with the given second which is nearest to the reference
+        let mut diff = second as i64 - reference.second() as i64;
+        if diff > 30 {
+            diff -= 60;
+        } else if diff < -30 {
+            diff += 60;
+        }
+        let snapped = reference.with_nanosecond(0)? + chrono::Duration::seconds(diff);
+        Some(snapped)
+    }
+
+    /// Return the reception time of a Type 27 long range AIS message from the tag block `c`
+    /// field. Satellite AIS feeds stamp each message on reception, which is the only reliable
+    /// time of Type 27 messages as they don't carry a timestamp. `None` is returned for other
+    /// messages and when the tag block has no time.
+    pub fn reception_time(&self) -> Option<DateTime<Utc>> {
+        match &self.message {
+            ParsedMessage::VesselDynamicData(vdd) if vdd.message_type == 27 => {
+                self.tag_block_time()
+            }
+            _ => None,
         }
     }
+
+    /// Return the tag block `c` field as `DateTime<Utc>`.
+    fn tag_block_time(&self) -> Option<DateTime<Utc>> {
+        let c = self.tag_block.as_ref().and_then(|tb| tb.timestamp)?;
+
+        // Values this large are milliseconds (seconds would be beyond year 5000)
+        let millis = if c >= 100_000_000_000 { c } else { c * 1000 };
+        Utc.timestamp_millis_opt(millis as i64).single()
+    }
+
+    /// Return the complete bits of an AIS message or `None` if the message isn't AIS or raw
+    /// bits aren't kept by the parser.
+    pub fn raw_bits(&self) -> Option<&RawBits> {
+        self.raw_bits.as_ref()
+    }
+
+    /// Return the number of fill bits declared by the sentence which completed an AIS message
+    /// (the last fragment of a multipart message) or `None` if the message isn't AIS or the
+    /// field is empty or malformed.
+    pub fn fill_bits(&self) -> Option<u8> {
+        self.fill_bits
+    }
 }
 
 /// Result from function `NmeaParser::parse_sentence()`. If the given sentence represents only a
 /// partial message `ParsedMessage::Incomplete` is returned.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ParsedMessage {
     /// The given sentence is only part of multi-sentence message and we need more data to
     /// create the actual result. State is stored in `NmeaParser` object.
     Incomplete,
 
+    /// AIS message of the given type which isn't accepted by the type filter set with
+    /// `NmeaParser::set_type_filter`. The message isn't decoded.
+    Filtered(u8),
+
+    /// AIS sentence with a payload identical to one seen recently, e.g. the same message
+    /// received by several receivers. Enabled with `NmeaParser::set_duplicate_window`. The
+    /// payload isn't decoded again.
+    Duplicate,
+
     /// AIS VDM/VDO t1, t2, t3, t18 and t27
     VesselDynamicData(ais::VesselDynamicData),
 
@@ -230,6 +404,129 @@ pub enum ParsedMessage {
 
     /// MWV
     Mwv(gnss::MwvData),
+
+    /// VDR
+    Vdr(gnss::VdrData),
+
+    /// BOD
+    Bod(gnss::BodData),
+
+    /// AAM
+    Aam(gnss::AamData),
+
+    /// DBK
+    Dbk(gnss::DbkData),
+
+    /// RSA
+    Rsa(gnss::RsaData),
+
+    /// VPW
+    Vpw(gnss::VpwData),
+
+    /// DSC
+    Dsc(gnss::DscData),
+
+    /// HSC
+    Hsc(gnss::HscData),
+
+    /// ZTG
+    Ztg(gnss::ZtgData),
+
+    /// ZFO
+    Zfo(gnss::ZfoData),
+
+    /// OSD
+    Osd(gnss::OsdData),
+
+    /// ALR
+    Alr(gnss::AlrData),
+
+    /// ACK
+    Ack(gnss::AckData),
+}
+
+impl ParsedMessage {
+    /// Return the navigation system identified from the talker ID of a GNSS sentence or `None`
+    /// if the message isn't a GNSS sentence.
+    pub fn nav_system(&self) -> Option<gnss::NavigationSystem> {
+        match self {
+            ParsedMessage::Gga(gga) => Some(gga.source),
+            ParsedMessage::Rmc(rmc) => Some(rmc.source),
+            ParsedMessage::Gns(gns) => Some(gns.source),
+            ParsedMessage::Gsa(gsa) => Some(gsa.source),
+            ParsedMessage::Gsv(gsvs) => gsvs.first().map(|gsv| gsv.source),
+            ParsedMessage::Vtg(vtg) => Some(vtg.source),
+            ParsedMessage::Gll(gll) => Some(gll.source),
+            ParsedMessage::Alm(alm) => Some(alm.source),
+            ParsedMessage::Dtm(dtm) => Some(dtm.source),
+            ParsedMessage::Mss(mss) => Some(mss.source),
+            ParsedMessage::Stn(stn) => Some(stn.source),
+            ParsedMessage::Vbw(vbw) => Some(vbw.source),
+            ParsedMessage::Zda(zda) => Some(zda.source),
+            ParsedMessage::Bod(bod) => Some(bod.source),
+            ParsedMessage::Aam(aam) => Some(aam.source),
+            ParsedMessage::Ztg(ztg) => Some(ztg.source),
+            ParsedMessage::Zfo(zfo) => Some(zfo.source),
+            ParsedMessage::Ack(ack) => Some(ack.source),
+            ParsedMessage::Alr(alr) => Some(alr.source),
+            ParsedMessage::Dbk(dbk) => Some(dbk.source),
+            ParsedMessage::Dbs(dbs) => Some(dbs.source),
+            ParsedMessage::Dpt(dpt) => Some(dpt.source),
+            ParsedMessage::Dsc(dsc) => Some(dsc.source),
+            ParsedMessage::Hdt(hdt) => Some(hdt.source),
+            ParsedMessage::Hsc(hsc) => Some(hsc.source),
+            ParsedMessage::Mtw(mtw) => Some(mtw.source),
+            ParsedMessage::Mwv(mwv) => Some(mwv.source),
+            ParsedMessage::Osd(osd) => Some(osd.source),
+            ParsedMessage::Rsa(rsa) => Some(rsa.source),
+            ParsedMessage::Vdr(vdr) => Some(vdr.source),
+            ParsedMessage::Vhw(vhw) => Some(vhw.source),
+            ParsedMessage::Vpw(vpw) => Some(vpw.source),
+            _ => None,
+        }
+    }
+
+    /// Return the AIS message type number (1-27) the message was decoded from or `None` if the
+    /// message isn't an AIS message.
+    pub fn ais_message_type(&self) -> Option<u8> {
+        match self {
+            ParsedMessage::Filtered(message_type) => Some(*message_type),
+            ParsedMessage::VesselDynamicData(vdd) => Some(vdd.message_type),
+            ParsedMessage::VesselStaticData(vsd) => Some(vsd.message_type),
+            ParsedMessage::BaseStationReport(_) => Some(4),
+            ParsedMessage::BinaryAddressedMessage(_) => Some(6),
+            ParsedMessage::BinaryBroadcastMessage(_) => Some(8),
+            ParsedMessage::StandardSarAircraftPositionReport(_) => Some(9),
+            ParsedMessage::UtcDateInquiry(_) => Some(10),
+            ParsedMessage::UtcDateResponse(_) => Some(11),
+            ParsedMessage::AddressedSafetyRelatedMessage(_) => Some(12),
+            ParsedMessage::SafetyRelatedAcknowledgement(_) => Some(13),
+            ParsedMessage::SafetyRelatedBroadcastMessage(_) => Some(14),
+            ParsedMessage::Interrogation(_) => Some(15),
+            ParsedMessage::AssignmentModeCommand(_) => Some(16),
+            ParsedMessage::DgnssBroadcastBinaryMessage(_) => Some(17),
+            ParsedMessage::DataLinkManagementMessage(_) => Some(20),
+            ParsedMessage::AidToNavigationReport(_) => Some(21),
+            ParsedMessage::ChannelManagement(_) => Some(22),
+            ParsedMessage::GroupAssignmentCommand(_) => Some(23),
+            ParsedMessage::SingleSlotBinaryMessage(_) => Some(25),
+            ParsedMessage::MultipleSlotBinaryMessage(_) => Some(26),
+            _ => None,
+        }
+    }
+
+    /// Encode the message as a complete NMEA sentence, including the checksum, using the given
+    /// two-character talker ID (e.g. "GP"). Returns `None` if the talker ID is malformed or if
+    /// encoding isn't supported for the message type.
+    pub fn to_sentence(&self, talker: &str) -> Option<String> {
+        if talker.len() != 2 || !talker.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        match self {
+            ParsedMessage::Rmc(rmc) => Some(gnss::rmc::encode(rmc, talker)),
+            _ => None,
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -245,6 +542,55 @@ pub trait LatLon {
     fn longitude(&self) -> Option<f64>;
 }
 
+/// Decoder for a sentence type, registered with `NmeaParser::register_handler`. Lets
+/// proprietary and other unsupported sentences be decoded without changes to the parser.
+pub trait SentenceHandler: Send + Sync {
+    /// Return the formatter of the handled sentences without the talker ID, e.g. `FOO` for
+    /// `$XXFOO`.
+    fn formatter(&self) -> &str;
+
+    /// Decode the sentence. `fields` contains the comma separated fields of the sentence without
+    /// the checksum, the first field being the address field (e.g. `$XXFOO`). `nav` is the
+    /// navigation system identified from the talker ID.
+    fn handle(
+        &self,
+        fields: &[&str],
+        nav: gnss::NavigationSystem,
+    ) -> Result<ParsedMessage, ParseError>;
+
+    /// Return the fragment information if the sentence is a part of a message split across
+    /// several sentences. `fields` is as in `handle`. The parser buffers the fragments and calls
+    /// `handle` once with the fields of all of them combined. The default implementation
+    /// treats every sentence as complete.
+    fn fragment(&self, _fields: &[&str]) -> Option<SentenceFragment> {
+        None
+    }
+}
+
+/// Position of a sentence within a message split across several sentences, returned by
+/// `SentenceHandler::fragment`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SentenceFragment {
+    /// Key identifying the fragments belonging to the same message, e.g. a sequential message ID
+    pub group_key: String,
+
+    /// Fragment number starting from 1
+    pub fragment_number: u8,
+
+    /// Total number of fragments in the message
+    pub fragment_count: u8,
+
+    /// Index of the first data field. Fields before it (address, fragment count, fragment number
+    /// etc.) are dropped from all but the first fragment when the fields are combined.
+    pub data_start: usize,
+}
+
+/// Read-only access to the absolute time contained by the implementing type.
+pub trait Timed {
+    /// Return the UTC time of the object. If the time is not available return `None`.
+    fn timestamp(&self) -> Option<DateTime<Utc>>;
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// NMEA sentence parser which keeps multi-sentence state between `parse_sentence` calls.
@@ -254,8 +600,29 @@ pub trait LatLon {
 pub struct NmeaParser {
     saved_fragments: HashMap<String, String>,
     saved_vsds: HashMap<u32, ais::VesselStaticData>,
+    saved_sentences: HashMap<String, String>,
+    saved_fill_bits: HashMap<String, u8>,
+    completed_sentences: Vec<String>,
+    gsa_accumulation: bool,
+    saved_gsas: Vec<gnss::GsaData>,
+    gns_accumulation: bool,
+    saved_gnss: Vec<gnss::GnsData>,
+    keep_raw_bits: bool,
+    completed_raw_bits: Option<RawBits>,
+    completed_fill_bits: Option<u8>,
+    fragment_progress: Option<(u8, u8)>,
+    fallback_handler: Option<FallbackHandler>,
+    sentence_handlers: HashMap<String, Arc<dyn SentenceHandler>>,
+    ais_type_filter: Option<u64>,
+    duplicate_window: usize,
+    recent_payloads: VecDeque<u64>,
+    permissive_coordinates: bool,
+    keep_invalid_gga_positions: bool,
 }
 
+/// Handler called with the formatter and the full sentence of unsupported sentence types.
+type FallbackHandler = Arc<dyn Fn(&str, &str) -> Option<ParsedMessage> + Send + Sync>;
+
 impl Default for NmeaParser {
     fn default() -> Self {
         Self::new()
@@ -268,6 +635,24 @@ impl NmeaParser {
         NmeaParser {
             saved_fragments: HashMap::new(),
             saved_vsds: HashMap::new(),
+            saved_sentences: HashMap::new(),
+            saved_fill_bits: HashMap::new(),
+            completed_sentences: Vec::new(),
+            gsa_accumulation: false,
+            saved_gsas: Vec::new(),
+            gns_accumulation: false,
+            saved_gnss: Vec::new(),
+            keep_raw_bits: false,
+            completed_raw_bits: None,
+            completed_fill_bits: None,
+            fragment_progress: None,
+            fallback_handler: None,
+            sentence_handlers: HashMap::new(),
+            ais_type_filter: None,
+            duplicate_window: 0,
+            recent_payloads: VecDeque::new(),
+            permissive_coordinates: false,
+            keep_invalid_gga_positions: false,
         }
     }
 
@@ -276,6 +661,271 @@ impl NmeaParser {
     pub fn reset(&mut self) {
         self.saved_fragments.clear();
         self.saved_vsds.clear();
+        self.saved_sentences.clear();
+        self.saved_fill_bits.clear();
+        self.completed_sentences.clear();
+        self.saved_gsas.clear();
+        self.saved_gnss.clear();
+        self.recent_payloads.clear();
+    }
+
+    /// Return the static data cached for each vessel keyed by MMSI, in arbitrary order. The
+    /// cache contains the vessels whose static data has been seen since the parser was created
+    /// or last reset.
+    pub fn known_vessels(&self) -> impl Iterator<Item = (&u32, &ais::VesselStaticData)> {
+        self.saved_vsds.iter()
+    }
+
+    /// Clear the cached static data and buffered multipart fragments of the given vessel.
+    /// Other vessels are not affected.
+    pub fn forget_vessel(&mut self, mmsi: u32) {
+        self.saved_vsds.remove(&mmsi);
+
+        // The MMSI is contained by the first fragment of a multipart message. All the fragments
+        // of the message share the sentence type, message ID and channel part of the key.
+        let messages: Vec<String> = self
+            .saved_fragments
+            .iter()
+            .filter_map(|(key, payload)| {
+                let message = key.strip_suffix(":frag_1")?;
+                let bv = parse_payload(payload.get(0..7)?).ok()?;
+                if pick_u64(&bv, 8, 30) as u32 == mmsi {
+                    Some(message.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let same_message = |key: &String| match key.rsplit_once(":frag_") {
+            Some((message, _)) => messages.iter().any(|m| m == message),
+            None => false,
+        };
+        self.saved_fragments.retain(|key, _| !same_message(key));
+        self.saved_sentences.retain(|key, _| !same_message(key));
+        self.saved_fill_bits
+            .retain(|key, _| !messages.iter().any(|m| m == key));
+    }
+
+    /// Remove the buffered fragments of incomplete AIS multipart messages and return them
+    /// ordered by sentence type, message ID, channel and fragment number. Buffered GSV
+    /// sentences are not affected.
+    pub fn drain_incomplete(&mut self) -> Vec<IncompleteFragment> {
+        let keys: Vec<String> = self
+            .saved_fragments
+            .keys()
+            .filter(|key| key.contains(":frag_"))
+            .cloned()
+            .collect();
+
+        self.saved_fill_bits.clear();
+        let mut fragments = Vec::new();
+        for key in keys {
+            let payload = self.saved_fragments.remove(&key).unwrap_or_default();
+            let sentence = self.saved_sentences.remove(&key);
+            let mut parts = key.splitn(4, ':');
+            let sentence_type = parts.next().unwrap_or("");
+            let message_id = parts.next().and_then(|s| s.parse::<u64>().ok());
+            let channel = parts.next().unwrap_or("");
+            let fragment_number = parts
+                .next()
+                .and_then(|s| s.strip_prefix("frag_"))
+                .and_then(|s| s.parse::<u8>().ok());
+            if let (Some(message_id), Some(fragment_number)) = (message_id, fragment_number) {
+                fragments.push(IncompleteFragment {
+                    sentence_type: sentence_type.into(),
+                    message_id,
+                    channel: channel.into(),
+                    fragment_number,
+                    payload,
+                    sentence,
+                });
+            }
+        }
+        fragments.sort_by(|a, b| {
+            (&a.sentence_type, a.message_id, &a.channel, a.fragment_number).cmp(&(
+                &b.sentence_type,
+                b.message_id,
+                &b.channel,
+                b.fragment_number,
+            ))
+        });
+        fragments
+    }
+
+    /// Enable or disable accumulation of GSA sentences. When enabled, the latest GSA of each
+    /// constellation is stored and the combined view is available from `combined_gsa`.
+    pub fn set_gsa_accumulation(&mut self, enabled: bool) {
+        self.gsa_accumulation = enabled;
+        if !enabled {
+            self.saved_gsas.clear();
+        }
+    }
+
+    /// Enable or disable accumulation of GNS sentences. When enabled, the GNS sentences of
+    /// different talkers describing the same fix are available from `combined_gns`.
+    pub fn set_gns_accumulation(&mut self, enabled: bool) {
+        self.gns_accumulation = enabled;
+        if !enabled {
+            self.saved_gnss.clear();
+        }
+    }
+
+    /// Enable or disable keeping the complete bits of AIS messages. When enabled, the bits are
+    /// available from `NmeaMessage::raw_bits`. Disabled by default.
+    pub fn keep_raw_bits(&mut self, enabled: bool) {
+        self.keep_raw_bits = enabled;
+    }
+
+    /// Register a handler for sentence types the parser doesn't support. The handler is called
+    /// with the formatter (e.g. `DBT`) and the full sentence. If it returns `Some`, the message
+    /// is returned instead of `ParseError::UnsupportedSentenceType`.
+    pub fn set_fallback_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&str, &str) -> Option<ParsedMessage> + Send + Sync + 'static,
+    {
+        self.fallback_handler = Some(Arc::new(handler));
+    }
+
+    /// Register a handler for the sentence type it reports with `SentenceHandler::formatter`.
+    /// Registered handlers are consulted before the built-in ones, so they can also replace the
+    /// decoding of supported sentence types. A handler registered earlier for the same
+    /// formatter is replaced.
+    pub fn register_handler(&mut self, handler: Box<dyn SentenceHandler>) {
+        self.sentence_handlers
+            .insert(handler.formatter().to_string(), Arc::from(handler));
+    }
+
+    /// Decode only AIS messages of the given types. Messages of other types are returned as
+    /// `ParsedMessage::Filtered` without decoding them.
+    pub fn set_type_filter(&mut self, types: &[u8]) {
+        self.ais_type_filter = Some(
+            types
+                .iter()
+                .filter(|t| **t < 64)
+                .fold(0, |mask, t| mask | (1 << t)),
+        );
+    }
+
+    /// Remove the AIS message type filter set with `set_type_filter`.
+    pub fn clear_type_filter(&mut self) {
+        self.ais_type_filter = None;
+    }
+
+    /// Suppress AIS messages whose payload is identical to one of the given number of most
+    /// recent AIS payloads. Multipart messages are compared after reassembly. Such messages are
+    /// returned as `ParsedMessage::Duplicate`. Zero disables the detection, which is the default.
+    pub fn set_duplicate_window(&mut self, window: usize) {
+        self.duplicate_window = window;
+        while self.recent_payloads.len() > window {
+            self.recent_payloads.pop_front();
+        }
+    }
+
+    /// Enable or disable the sanity check of GGA, GLL, GNS and RMC latitudes. When enabled, a
+    /// latitude field which can't hold a latitude, for example because a receiver has emitted
+    /// the longitude in its place, results `None` latitude instead of an error or an impossible
+    /// value. Disabled by default.
+    pub fn set_permissive_coordinates(&mut self, enabled: bool) {
+        self.permissive_coordinates = enabled;
+    }
+
+    /// Enable or disable keeping the coordinates of GGA sentences with fix quality 0 (invalid).
+    /// Some receivers emit the last known, stale position with an invalid fix. When disabled,
+    /// such positions are returned as `None`. An empty quality field doesn't drop the position.
+    /// Disabled by default.
+    pub fn set_keep_invalid_gga_positions(&mut self, enabled: bool) {
+        self.keep_invalid_gga_positions = enabled;
+    }
+
+    /// Return the active satellites and DOP values merged from the latest GSA sentence of each
+    /// constellation or `None` if none has been received. Requires GSA accumulation to be
+    /// enabled with `set_gsa_accumulation`.
+    pub fn combined_gsa(&self) -> Option<gnss::GsaData> {
+        gnss::gsa::merge(&self.saved_gsas)
+    }
+
+    /// Return the GNS sentences of the latest fix, one per talker. Requires GNS accumulation to
+    /// be enabled with `set_gns_accumulation`.
+    ///
+    /// Grouping is best-effort: sentences are considered to belong to the same fix when they
+    /// have identical timestamps, and a sentence with a different timestamp starts a new fix.
+    pub fn combined_gns(&self) -> &[gnss::GnsData] {
+        &self.saved_gnss
+    }
+
+    /// Buffer a fragment of a sentence decoded by a registered handler and call the handler with
+    /// the combined fields once all the fragments have been received.
+    fn handle_sentence_fragment(
+        &mut self,
+        handler: &dyn SentenceHandler,
+        fragment: SentenceFragment,
+        fields: &[&str],
+        nav_system: gnss::NavigationSystem,
+    ) -> Result<ParsedMessage, ParseError> {
+        if fragment.fragment_number == 0 || fragment.fragment_number > fragment.fragment_count {
+            return Err(format!(
+                "Invalid fragment number {} of {} in {}",
+                fragment.fragment_number,
+                fragment.fragment_count,
+                handler.formatter()
+            )
+            .into());
+        }
+
+        let key_prefix = format!("{}:{}:part_", handler.formatter(), fragment.group_key);
+        let data = if fragment.fragment_number == 1 {
+            fields.join(",")
+        } else {
+            fields
+                .get(fragment.data_start..)
+                .unwrap_or_default()
+                .join(",")
+        };
+        self.push_string(format!("{}{}", key_prefix, fragment.fragment_number), data);
+
+        let complete = (1..=fragment.fragment_count)
+            .all(|i| self.contains_key(format!("{}{}", key_prefix, i)));
+        if !complete {
+            let have = (1..=fragment.fragment_count)
+                .filter(|i| self.contains_key(format!("{}{}", key_prefix, i)))
+                .count();
+            self.set_fragment_progress(have as u8, fragment.fragment_count);
+            return Ok(ParsedMessage::Incomplete);
+        }
+
+        let mut combined: Vec<String> = Vec::new();
+        for i in 1..=fragment.fragment_count {
+            if let Some(data) = self.pull_string(format!("{}{}", key_prefix, i)) {
+                if !data.is_empty() || i == 1 {
+                    combined.push(data);
+                }
+            }
+        }
+        let combined = combined.join(",");
+        let combined_fields: Vec<&str> = combined.split(',').collect();
+        handler.handle(&combined_fields, nav_system)
+    }
+
+    /// Check whether the given complete AIS payload is among the recent ones and remember it.
+    /// Always false if the duplicate detection is disabled.
+    fn is_duplicate_payload(&mut self, payload: &str) -> bool {
+        if self.duplicate_window == 0 {
+            return false;
+        }
+        let hash = payload_hash(payload);
+        if self.recent_payloads.contains(&hash) {
+            return true;
+        }
+        if self.recent_payloads.len() >= self.duplicate_window {
+            self.recent_payloads.pop_front();
+        }
+        self.recent_payloads.push_back(hash);
+        false
+    }
+
+    /// Record the progress of the multipart message the current sentence belongs to.
+    fn set_fragment_progress(&mut self, have: u8, need: u8) {
+        self.fragment_progress = Some((have, need));
     }
 
     /// Push string-to-string mapping to store.
@@ -308,6 +958,54 @@ impl NmeaParser {
         self.saved_vsds.remove(&mmsi)
     }
 
+    /// Push GSA to store replacing the earlier one of the same constellation. GSAs of
+    /// unidentified constellations are kept side by side until one of their satellites repeats,
+    /// which starts a new fix.
+    fn push_gsa(&mut self, gsa: gnss::GsaData) {
+        match gnss::gsa::constellation_key(&gsa) {
+            Some(key) => {
+                self.saved_gsas
+                    .retain(|saved| gnss::gsa::constellation_key(saved) != Some(key));
+            }
+            None => {
+                let repeated = self.saved_gsas.iter().any(|saved| {
+                    gnss::gsa::constellation_key(saved).is_none()
+                        && saved
+                            .prn_numbers
+                            .iter()
+                            .any(|prn| gsa.prn_numbers.contains(prn))
+                });
+                if repeated {
+                    self.saved_gsas
+                        .retain(|saved| gnss::gsa::constellation_key(saved).is_some());
+                }
+                let unidentified = self
+                    .saved_gsas
+                    .iter()
+                    .filter(|saved| gnss::gsa::constellation_key(saved).is_none())
+                    .count();
+                if unidentified >= gnss::gsa::MAX_UNIDENTIFIED_GSAS {
+                    if let Some(pos) = self
+                        .saved_gsas
+                        .iter()
+                        .position(|saved| gnss::gsa::constellation_key(saved).is_none())
+                    {
+                        self.saved_gsas.remove(pos);
+                    }
+                }
+            }
+        }
+        self.saved_gsas.push(gsa);
+    }
+
+    /// Push GNS to store replacing the earlier one of the same talker. Sentences of earlier
+    /// fixes are dropped.
+    fn push_gns(&mut self, gns: gnss::GnsData) {
+        self.saved_gnss
+            .retain(|saved| saved.timestamp == gns.timestamp && saved.source != gns.source);
+        self.saved_gnss.push(gns);
+    }
+
     /// Return number of MMSI-to-VesselStaticData mappings in store.
     fn vsds_count(&self) -> usize {
         self.saved_vsds.len()
@@ -337,8 +1035,46 @@ impl NmeaParser {
         
         // Parse the NMEA sentence part
         let parsed_message = self.parse_sentence_internal(nmea_sentence)?;
-        
-        Ok(NmeaMessage::new(parsed_message, tag_block))
+
+        // Multipart messages leave their fragments to `completed_sentences`
+        let original_sentences = if self.completed_sentences.is_empty() {
+            vec![nmea_sentence.to_string()]
+        } else {
+            core::mem::take(&mut self.completed_sentences)
+        };
+
+        Ok(NmeaMessage {
+            message: parsed_message,
+            tag_block,
+            original_sentences,
+            raw_bits: self.completed_raw_bits.take(),
+            fill_bits: self.completed_fill_bits.take(),
+        })
+    }
+
+    /// Parse NMEA sentence like `parse_sentence_with_tags` and return the result together with
+    /// the trimmed input sentence.
+    pub fn parse_sentence_owned(&mut self, sentence: &str) -> Result<OwnedNmeaMessage, ParseError> {
+        let raw = sentence.trim();
+        Ok(OwnedNmeaMessage {
+            raw: raw.to_string(),
+            parsed: self.parse_sentence_with_tags(raw)?,
+        })
+    }
+
+    /// Parse NMEA sentence given as bytes like `parse_sentence_with_tags`. NMEA sentences are
+    /// ASCII, so any byte outside the ASCII range results `ParseError::InvalidSentence`
+    /// telling the byte and its position.
+    pub fn parse_bytes(&mut self, bytes: &[u8]) -> Result<NmeaMessage, ParseError> {
+        if let Some(pos) = bytes.iter().position(|b| !b.is_ascii()) {
+            return Err(ParseError::InvalidSentence(format!(
+                "Non-ASCII byte 0x{:02X} at position {}",
+                bytes[pos], pos
+            )));
+        }
+        let sentence = core::str::from_utf8(bytes)
+            .map_err(|e| ParseError::InvalidSentence(format!("{}", e)))?;
+        self.parse_sentence_with_tags(sentence)
     }
 
     /// Parse NMEA sentence into `ParsedMessage` enum. If the given sentence is part of
@@ -351,83 +1087,87 @@ impl NmeaParser {
         Ok(result.message)
     }
 
-    /// Internal function to parse the actual NMEA sentence (without tag blocks)
-    #[inline]
-    fn parse_sentence_internal(&mut self, sentence: &str) -> Result<ParsedMessage, ParseError> {
-        // Shed characters prefixing the message if they exist
-        let sentence = {
-            if let Some(start_idx) = sentence.find(['$', '!']) {
-                &sentence[start_idx..]
-            } else {
-                return Err(ParseError::InvalidSentence(format!(
-                    "Invalid NMEA sentence: {}",
-                    sentence
-                )));
+    /// Parse NMEA sentence like `parse_sentence` but return
+    /// `ParseError::NeedMoreFragments` instead of `ParsedMessage::Incomplete` when the sentence
+    /// is part of a multipart message which isn't complete yet. The fragments are buffered
+    /// as with `parse_sentence`.
+    pub fn parse_sentence_strict(&mut self, sentence: &str) -> Result<ParsedMessage, ParseError> {
+        match self.parse_sentence(sentence)? {
+            ParsedMessage::Incomplete => {
+                let (have, need) = self.fragment_progress.unwrap_or((0, 0));
+                Err(ParseError::NeedMoreFragments { have, need })
             }
-        };
+            msg => Ok(msg),
+        }
+    }
 
-        // Calculate NMEA checksum and compare it to the given one. Also, remove the checksum part
-        // from the sentence to simplify next processing steps.
-        let (sentence, checksum_hex_given) = {
-            if let Some(pos) = sentence.rfind('*') {
-                if pos + 3 <= sentence.len() {
-                    (
-                        &sentence[0..pos],
-                        &sentence[(pos + 1)..(pos + 3)],
-                    )
-                } else {
-                    debug!("Invalid checksum found for sentence: {}", sentence);
-                    (&sentence[0..pos], "")
-                }
-            } else {
-                debug!("No checksum found for sentence: {}", sentence);
-                (sentence, "")
-            }
-        };
-        
-        // Only validate checksum if one was provided
-        if !checksum_hex_given.is_empty() {
-            let mut checksum = 0u8;
-            for b in sentence.bytes().skip(1) {
-                checksum ^= b;
-            }
-            
-            // Parse the hex checksum directly without allocating
-            let expected_checksum = u8::from_str_radix(checksum_hex_given, 16)
-                .map_err(|_| ParseError::CorruptedSentence(format!(
-                    "Invalid checksum format: {}",
-                    checksum_hex_given
-                )))?;
-            
-            if checksum != expected_checksum {
-                return Err(ParseError::CorruptedSentence(format!(
-                    "Corrupted NMEA sentence: \"{:02X?}\" != \"{:02X?}\"",
-                    checksum, expected_checksum
-                )));
-            }
+    /// Parse NMEA sentence like `parse_sentence` but return `None` instead of
+    /// `ParsedMessage::Incomplete` when the sentence is part of a multipart message which
+    /// isn't complete yet.
+    pub fn parse_complete(&mut self, sentence: &str) -> Result<Option<ParsedMessage>, ParseError> {
+        match self.parse_sentence(sentence)? {
+            ParsedMessage::Incomplete => Ok(None),
+            msg => Ok(Some(msg)),
         }
+    }
 
-        // Pick sentence type
-        let sentence_type = {
-            if let Some(i) = sentence.find(',') {
-                &sentence[0..i]
-            } else {
-                return Err(ParseError::InvalidSentence(format!(
-                    "Invalid NMEA sentence: {}",
-                    sentence
-                )));
+    /// Parse all sentences of the given buffer, such as a UDP datagram. The buffer is split into
+    /// lines at CR and LF characters and empty lines are skipped. The results are returned in
+    /// the order of the lines and multipart fragments produce `ParsedMessage::Incomplete`.
+    pub fn parse_batch(&mut self, data: &str) -> Vec<Result<ParsedMessage, ParseError>> {
+        data.split(['\r', '\n'])
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| self.parse_sentence(line))
+            .collect()
+    }
+
+    /// Parse the lines of a log one by one. The returned iterator yields the one-based line
+    /// number with the result of each non-empty line. Incomplete multipart messages are yielded
+    /// as `Ok(None)`. Errors don't stop the iteration so the whole log is always processed.
+    pub fn replay<'a>(
+        &'a mut self,
+        lines: impl Iterator<Item = &'a str> + 'a,
+    ) -> impl Iterator<Item = (usize, Result<Option<ParsedMessage>, ParseError>)> + 'a {
+        lines
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .map(move |(line_number, line)| (line_number, self.parse_complete(line)))
+    }
+
+    /// Split the given sentence into its comma-separated fields for debugging and inspecting
+    /// unsupported sentences. The tag block and the checksum are removed and the checksum is
+    /// verified if present. The first field is the sentence formatter including the talker ID,
+    /// e.g. `$GPGGA`.
+    pub fn split_fields(sentence: &str) -> Result<Vec<&str>, ParseError> {
+        let sentence = sentence.trim();
+        let sentence = if let Some(tagged) = sentence.strip_prefix('\\') {
+            match tagged.find('\\') {
+                Some(end_pos) => tagged[end_pos + 1..].trim_start(),
+                None => {
+                    return Err(ParseError::InvalidSentence(
+                        "Tag block not properly closed".to_string(),
+                    ));
+                }
             }
+        } else {
+            sentence
         };
+        Ok(frame_sentence(sentence)?.split(',').collect())
+    }
 
-        // Validate sentence type characters (using bytes is faster than chars)
-        for &b in sentence_type.as_bytes() {
-            if !b.is_ascii_alphanumeric() && b != b'$' && b != b'!' {
-                return Err(ParseError::InvalidSentence(format!(
-                    "Invalid characters in sentence type: {}",
-                    sentence_type
-                )));
-            }
-        }
+    /// Internal function to parse the actual NMEA sentence (without tag blocks)
+    #[inline]
+    fn parse_sentence_internal(&mut self, sentence: &str) -> Result<ParsedMessage, ParseError> {
+        self.completed_sentences.clear();
+        self.completed_raw_bits = None;
+        self.completed_fill_bits = None;
+        self.fragment_progress = None;
+        let original_sentence = sentence;
+
+        let sentence = frame_sentence(sentence)?;
+        let sentence_type = &sentence[0..sentence.find(',').unwrap_or(sentence.len())];
 
         let (nav_system, station, sentence_type) = if sentence_type.starts_with('$') {
             // Identify GNSS system by talker ID.
@@ -477,22 +1217,42 @@ impl NmeaParser {
             )
         };
 
+        // Registered handlers take precedence over the built-in ones
+        if let Some(handler) = self
+            .sentence_handlers
+            .get(sentence_type.get(1..).unwrap_or(""))
+        {
+            let handler = handler.clone();
+            let fields: Vec<&str> = sentence.split(',').collect();
+            return match handler.fragment(&fields) {
+                Some(fragment) => {
+                    self.handle_sentence_fragment(handler.as_ref(), fragment, &fields, nav_system)
+                }
+                None => handler.handle(&fields, nav_system),
+            };
+        }
+
         // Handle sentence types
         match sentence_type.as_str() {
             // $xxGGA - Global Positioning System Fix Data
-            "$GGA" => gnss::gga::handle(sentence, nav_system),
+            "$GGA" => gnss::gga::handle(
+                sentence,
+                nav_system,
+                self.permissive_coordinates,
+                self.keep_invalid_gga_positions,
+            ),
             // $xxRMC - Recommended minimum specific GPS/Transit data
-            "$RMC" => gnss::rmc::handle(sentence, nav_system),
+            "$RMC" => gnss::rmc::handle(sentence, nav_system, self.permissive_coordinates),
             // $xxGNS - GNSS fix data
-            "$GNS" => gnss::gns::handle(sentence, nav_system),
+            "$GNS" => gnss::gns::handle(sentence, nav_system, self),
             // $xxGSA - GPS DOP and active satellites
-            "$GSA" => gnss::gsa::handle(sentence, nav_system),
+            "$GSA" => gnss::gsa::handle(sentence, nav_system, self),
             // $xxGSV - GPS Satellites in view
             "$GSV" => gnss::gsv::handle(sentence, nav_system, self),
             // $xxVTG - Track made good and ground speed
             "$VTG" => gnss::vtg::handle(sentence, nav_system),
             // $xxGLL - Geographic position, latitude / longitude
-            "$GLL" => gnss::gll::handle(sentence, nav_system),
+            "$GLL" => gnss::gll::handle(sentence, nav_system, self.permissive_coordinates),
             // $xxALM - Almanac Data
             "$ALM" => gnss::alm::handle(sentence, nav_system),
             // $xxDTM - Datum reference
@@ -514,6 +1274,7 @@ impl NmeaParser {
                 let mut message_id = None;
                 let mut radio_channel_code = None;
                 let mut payload_string: String = "".into();
+                let mut fill_bits = None;
                 for (num, s) in sentence.split(',').enumerate() {
                     match num {
                         1 => {
@@ -553,18 +1314,25 @@ impl NmeaParser {
                             payload_string = s.to_string();
                         }
                         6 => {
-                            // fill bits
+                            fill_bits = s.parse::<u8>().ok();
                         }
                         _ => {}
                     }
                 }
 
+                if payload_string.is_empty() {
+                    return Err(ParseError::InvalidSentence("empty AIS payload".into()));
+                }
+
                 // Try parse the payload
                 let mut bv: Option<BitVec> = None;
                 match fragment_count {
                     1 => {
                         // Single fragment message - parse directly
-                        bv = parse_payload(&payload_string).ok();
+                        if self.is_duplicate_payload(&payload_string) {
+                            return Ok(ParsedMessage::Duplicate);
+                        }
+                        bv = Some(parse_payload(&payload_string)?);
                     }
                     2..=4 => {
                         // Multipart message (2-4 fragments)
@@ -585,10 +1353,25 @@ impl NmeaParser {
                             let mut fragment_key = base_key.clone();
                             fragment_key.push_str(":frag_");
                             let _ = write!(&mut fragment_key, "{}", fragment_number);
+                            self.saved_sentences
+                                .insert(fragment_key.clone(), original_sentence.to_string());
                             self.push_string(fragment_key, payload_string.clone());
+
+                            // The fill bits are declared by the last fragment
+                            if fragment_number == fragment_count {
+                                match fill_bits {
+                                    Some(fill_bits) => {
+                                        self.saved_fill_bits.insert(base_key.clone(), fill_bits);
+                                    }
+                                    None => {
+                                        self.saved_fill_bits.remove(&base_key);
+                                    }
+                                }
+                            }
                             
                             // Check if we have all fragments and build combined payload more efficiently
                             let mut complete = true;
+                            let mut have = 0;
                             let mut combined_payload = String::new();
                             
                             // Pre-allocate estimated capacity to avoid reallocation
@@ -602,9 +1385,9 @@ impl NmeaParser {
                                 
                                 if let Some(fragment_payload) = self.saved_fragments.get(&key) {
                                     combined_payload.push_str(fragment_payload);
+                                    have += 1;
                                 } else {
                                     complete = false;
-                                    break;
                                 }
                             }
                             
@@ -615,12 +1398,21 @@ impl NmeaParser {
                                     key.push_str(":frag_");
                                     let _ = write!(&mut key, "{}", frag_num);
                                     self.saved_fragments.remove(&key);
+                                    if let Some(s) = self.saved_sentences.remove(&key) {
+                                        self.completed_sentences.push(s);
+                                    }
                                 }
+                                fill_bits = self.saved_fill_bits.remove(&base_key);
                                 
                                 // Parse the combined payload
-                                bv = parse_payload(&combined_payload).ok();
+                                if self.is_duplicate_payload(&combined_payload) {
+                                    return Ok(ParsedMessage::Duplicate);
+                                }
+                                bv = Some(parse_payload(&combined_payload)?);
+                            } else {
+                                // Return Incomplete and wait for more fragments
+                                self.set_fragment_progress(have, fragment_count);
                             }
-                            // If not complete, return Incomplete and wait for more fragments
                         } else {
                             warn!(
                                 "NMEA message_id missing from multipart message {}",
@@ -637,7 +1429,29 @@ impl NmeaParser {
                 }
 
                 if let Some(bv) = bv {
+                    self.completed_fill_bits = fill_bits;
+                    if self.keep_raw_bits {
+                        self.completed_raw_bits = Some(RawBits {
+                            data: pack_bits(&bv),
+                            bit_length: bv.len(),
+                        });
+                    }
+
                     let message_type = pick_u64(&bv, 0, 6);
+                    if let Some(mask) = self.ais_type_filter {
+                        if mask & (1 << message_type) == 0 {
+                            return Ok(ParsedMessage::Filtered(message_type as u8));
+                        }
+                    }
+                    let min_bits = ais::min_bits_for_type(message_type as u8);
+                    if bv.len() < min_bits {
+                        return Err(ParseError::InvalidSentence(format!(
+                            "Type {} message too short: {} bits (minimum {} required)",
+                            message_type,
+                            bv.len(),
+                            min_bits
+                        )));
+                    }
                     match message_type {
                         // Position report with SOTDMA/ITDMA
                         1..=3 => ais::vdm_t1t2t3::handle(&bv, station, own_vessel),
@@ -704,18 +1518,120 @@ impl NmeaParser {
                     Ok(ParsedMessage::Incomplete)
                 }
             }
-            "$DPT" => gnss::dpt::handle(sentence),
-            "$DBS" => gnss::dbs::handle(sentence),
-            "$MTW" => gnss::mtw::handle(sentence),
-            "$VHW" => gnss::vhw::handle(sentence),
-            "$HDT" => gnss::hdt::handle(sentence),
-            "$MWV" => gnss::mwv::handle(sentence),
-            _ => Err(ParseError::UnsupportedSentenceType(format!(
-                "Unsupported sentence type: {}",
+            "$DPT" => gnss::dpt::handle(sentence, nav_system),
+            "$DBS" => gnss::dbs::handle(sentence, nav_system),
+            "$MTW" => gnss::mtw::handle(sentence, nav_system),
+            "$VHW" => gnss::vhw::handle(sentence, nav_system),
+            "$HDT" => gnss::hdt::handle(sentence, nav_system),
+            "$MWV" => gnss::mwv::handle(sentence, nav_system),
+            "$VDR" => gnss::vdr::handle(sentence, nav_system),
+            "$BOD" => gnss::bod::handle(sentence, nav_system),
+            "$AAM" => gnss::aam::handle(sentence, nav_system),
+            "$DBK" => gnss::dbk::handle(sentence, nav_system),
+            "$RSA" => gnss::rsa::handle(sentence, nav_system),
+            "$VPW" => gnss::vpw::handle(sentence, nav_system),
+            "$DSC" => gnss::dsc::handle(sentence, nav_system),
+            "$HSC" => gnss::hsc::handle(sentence, nav_system),
+            "$ZTG" => gnss::ztg::handle(sentence, nav_system),
+            "$ZFO" => gnss::zfo::handle(sentence, nav_system),
+            "$OSD" => gnss::osd::handle(sentence, nav_system),
+            "$ALR" => gnss::alr::handle(sentence, nav_system),
+            "$ACK" => gnss::ack::handle(sentence, nav_system),
+            _ => {
+                if let Some(handler) = &self.fallback_handler {
+                    let formatter = sentence_type.get(1..).unwrap_or("");
+                    if let Some(msg) = handler(formatter, original_sentence) {
+                        return Ok(msg);
+                    }
+                }
+                Err(ParseError::UnsupportedSentenceType(format!(
+                    "Unsupported sentence type: {}",
+                    sentence_type
+                )))
+            }
+        }
+    }
+}
+
+/// Shed characters prefixing the sentence, verify the checksum if one is given and the sentence
+/// type characters. Return the sentence without the checksum.
+fn frame_sentence(sentence: &str) -> Result<&str, ParseError> {
+    // Shed characters prefixing the message if they exist
+    let sentence = {
+        if let Some(start_idx) = sentence.find(['$', '!']) {
+            &sentence[start_idx..]
+        } else {
+            return Err(ParseError::InvalidSentence(format!(
+                "Invalid NMEA sentence: {}",
+                sentence
+            )));
+        }
+    };
+
+    // Calculate NMEA checksum and compare it to the given one. Also, remove the checksum part
+    // from the sentence to simplify next processing steps.
+    let (sentence, checksum_hex_given) = {
+        if let Some(pos) = sentence.rfind('*') {
+            if pos + 3 <= sentence.len() {
+                (
+                    &sentence[0..pos],
+                    &sentence[(pos + 1)..(pos + 3)],
+                )
+            } else {
+                debug!("Invalid checksum found for sentence: {}", sentence);
+                (&sentence[0..pos], "")
+            }
+        } else {
+            debug!("No checksum found for sentence: {}", sentence);
+            (sentence, "")
+        }
+    };
+    
+    // Only validate checksum if one was provided
+    if !checksum_hex_given.is_empty() {
+        let mut checksum = 0u8;
+        for b in sentence.bytes().skip(1) {
+            checksum ^= b;
+        }
+        
+        // Parse the hex checksum directly without allocating
+        let expected_checksum = u8::from_str_radix(checksum_hex_given, 16)
+            .map_err(|_| ParseError::CorruptedSentence(format!(
+                "Invalid checksum format: {}",
+                checksum_hex_given
+            )))?;
+        
+        if checksum != expected_checksum {
+            return Err(ParseError::CorruptedSentence(format!(
+                "Corrupted NMEA sentence: \"{:02X?}\" != \"{:02X?}\"",
+                checksum, expected_checksum
+            )));
+        }
+    }
+
+    // Pick sentence type
+    let sentence_type = {
+        if let Some(i) = sentence.find(',') {
+            &sentence[0..i]
+        } else {
+            return Err(ParseError::InvalidSentence(format!(
+                "Invalid NMEA sentence: {}",
+                sentence
+            )));
+        }
+    };
+
+    // Validate sentence type characters (using bytes is faster than chars)
+    for &b in sentence_type.as_bytes() {
+        if !b.is_ascii_alphanumeric() && b != b'$' && b != b'!' {
+            return Err(ParseError::InvalidSentence(format!(
+                "Invalid characters in sentence type: {}",
                 sentence_type
-            ))),
+            )));
         }
     }
+
+    Ok(sentence)
 }
 
 #[cfg(test)]
@@ -779,6 +1695,15 @@ mod test {
             .is_some());
     }
 
+    #[test]
+    fn test_parse_empty_ais_payload() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,,0*26"),
+            Err(ParseError::InvalidSentence(String::from("empty AIS payload")))
+        );
+    }
+
     #[test]
     fn test_parse_invalid_utc() {
         // Try a sentence with invalite utc
@@ -813,33 +1738,250 @@ mod test {
     }
 
     #[test]
-    fn test_parse_invalid_talker() {
-        // Try parse malformed sentences
+    fn test_fill_bits() {
         let mut p = NmeaParser::new();
-        assert_eq!(
-            p.parse_sentence("$QQ,*2C"),
-            Err(ParseError::UnsupportedSentenceType(String::from(
-                "Unsupported sentence type: $QQ"
-            )))
-        );
-        assert_eq!(
-            p.parse_sentence("$A,a0,*10"),
-            Err(ParseError::InvalidSentence(String::from(
-                "Invalid talker identifier"
-            )))
-        );
-        assert_eq!(
-            p.parse_sentence("$,0a,*51"),
-            Err(ParseError::InvalidSentence(String::from(
-                "Invalid talker identifier"
-            )))
-        );
+        let msg = p
+            .parse_sentence_with_tags("!AIVDM,1,1,,B,6B?n;be:cbapalgc;i6?Ow4,2*4A")
+            .unwrap();
+        assert_eq!(msg.fill_bits(), Some(2));
+
+        let msg = p
+            .parse_sentence_with_tags("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        assert_eq!(msg.fill_bits(), Some(0));
+
+        let msg = p
+            .parse_sentence_with_tags(
+                "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            )
+            .unwrap();
+        assert_eq!(msg.fill_bits(), None);
     }
 
     #[test]
-    fn test_nmea_parser() {
+    fn test_fill_bits_out_of_order() {
         let mut p = NmeaParser::new();
-
+        let msg = p
+            .parse_sentence_with_tags("!AIVDM,2,2,3,B,1@0000000000000,2*55")
+            .unwrap();
+        assert_eq!(msg.message, ParsedMessage::Incomplete);
+        assert_eq!(msg.fill_bits(), None);
+
+        // The fill bits come from the last fragment even if it arrived first
+        let msg = p
+            .parse_sentence_with_tags(
+                "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E",
+            )
+            .unwrap();
+        assert!(matches!(msg.message, ParsedMessage::VesselStaticData(_)));
+        assert_eq!(msg.fill_bits(), Some(2));
+        assert!(p.saved_fill_bits.is_empty());
+    }
+
+    #[test]
+    fn test_ais_message_type() {
+        let mut p = NmeaParser::new();
+        let type1 = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        let type3 = p
+            .parse_sentence("!AIVDM,1,1,,A,38Id705000rRVJhE7cl9n;160000,0*40")
+            .unwrap();
+        assert!(matches!(type1, ParsedMessage::VesselDynamicData(_)));
+        assert!(matches!(type3, ParsedMessage::VesselDynamicData(_)));
+        assert_eq!(type1.ais_message_type(), Some(1));
+        assert_eq!(type3.ais_message_type(), Some(3));
+
+        let gga = p
+            .parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47")
+            .unwrap();
+        assert_eq!(gga.ais_message_type(), None);
+        assert_eq!(ParsedMessage::Incomplete.ais_message_type(), None);
+    }
+
+    #[test]
+    fn test_register_handler() {
+        struct FooHandler;
+
+        impl SentenceHandler for FooHandler {
+            fn formatter(&self) -> &str {
+                "FOO"
+            }
+
+            fn handle(
+                &self,
+                fields: &[&str],
+                nav: gnss::NavigationSystem,
+            ) -> Result<ParsedMessage, ParseError> {
+                assert_eq!(fields[0], "$XXFOO");
+                assert_eq!(nav, gnss::NavigationSystem::Other);
+                let depth = fields.get(1).unwrap_or(&"");
+                Ok(ParsedMessage::Dbk(gnss::DbkData {
+                    source: nav,
+                    depth_feet: None,
+                    depth_meters: Some(
+                        depth
+                            .parse()
+                            .map_err(|_| format!("Invalid FOO depth: {}", depth))?,
+                    ),
+                    depth_fathoms: None,
+                }))
+            }
+        }
+
+        let mut p = NmeaParser::new();
+        let sentence = "$XXFOO,12.5,M*13";
+        assert_eq!(
+            p.parse_sentence(sentence),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $FOO"
+            )))
+        );
+
+        p.register_handler(Box::new(FooHandler));
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Dbk(dbk)) => {
+                assert_eq!(dbk.depth_meters, Some(12.5));
+            }
+            Ok(_) => panic!("Unexpected message type"),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        assert_eq!(
+            p.parse_sentence("$XXFOO,deep,M*1F"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Invalid FOO depth: deep"
+            )))
+        );
+
+        // Built-in handlers are still used for other sentence types
+        assert!(matches!(
+            p.parse_sentence("$GPHDT,274.07,T*03"),
+            Ok(ParsedMessage::Hdt(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_fragmented_handler() {
+        struct BarHandler;
+
+        impl SentenceHandler for BarHandler {
+            fn formatter(&self) -> &str {
+                "PXXBAR"
+            }
+
+            fn handle(
+                &self,
+                fields: &[&str],
+                nav: gnss::NavigationSystem,
+            ) -> Result<ParsedMessage, ParseError> {
+                assert_eq!(fields, ["$PXXBAR", "2", "1", "7", "10.5", "M", "20.5", "M"]);
+                Ok(ParsedMessage::Dbk(gnss::DbkData {
+                    source: nav,
+                    depth_feet: None,
+                    depth_meters: fields[4].parse().ok(),
+                    depth_fathoms: fields[6].parse().ok(),
+                }))
+            }
+
+            fn fragment(&self, fields: &[&str]) -> Option<SentenceFragment> {
+                Some(SentenceFragment {
+                    group_key: fields.get(3)?.to_string(),
+                    fragment_number: fields.get(2)?.parse().ok()?,
+                    fragment_count: fields.get(1)?.parse().ok()?,
+                    data_start: 4,
+                })
+            }
+        }
+
+        let mut p = NmeaParser::new();
+        p.register_handler(Box::new(BarHandler));
+
+        // Fragments arriving out of order are combined in fragment number order
+        assert_eq!(
+            p.parse_sentence("$PXXBAR,2,2,7,20.5,M*4E"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("$PXXBAR,2,1,7,10.5,M*4E") {
+            Ok(ParsedMessage::Dbk(dbk)) => {
+                assert_eq!(dbk.depth_meters, Some(10.5));
+                assert_eq!(dbk.depth_fathoms, Some(20.5));
+            }
+            Ok(_) => panic!("Unexpected message type"),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        assert_eq!(p.strings_count(), 0);
+    }
+
+    #[test]
+    fn test_fallback_handler() {
+        let mut p = NmeaParser::new();
+        let sentence = "$SDDBT,7.8,f,2.4,M,1.3,F*0D";
+        assert_eq!(
+            p.parse_sentence(sentence),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $DBT"
+            )))
+        );
+
+        p.set_fallback_handler(|formatter, sentence| {
+            if formatter != "DBT" {
+                return None;
+            }
+            let split: Vec<&str> = sentence.split(',').collect();
+            Some(ParsedMessage::Dbk(gnss::DbkData {
+                source: gnss::NavigationSystem::Other,
+                depth_feet: split.get(1).and_then(|s| s.parse().ok()),
+                depth_meters: split.get(3).and_then(|s| s.parse().ok()),
+                depth_fathoms: None,
+            }))
+        });
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Dbk(dbk)) => {
+                assert_eq!(dbk.depth_feet, Some(7.8));
+                assert_eq!(dbk.depth_meters, Some(2.4));
+                assert_eq!(dbk.depth_fathoms, None);
+            }
+            Ok(_) => panic!("Unexpected message type"),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Handler declines other sentence types
+        assert_eq!(
+            p.parse_sentence("$QQ,*2C"),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $QQ"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_talker() {
+        // Try parse malformed sentences
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("$QQ,*2C"),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $QQ"
+            )))
+        );
+        assert_eq!(
+            p.parse_sentence("$A,a0,*10"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Invalid talker identifier"
+            )))
+        );
+        assert_eq!(
+            p.parse_sentence("$,0a,*51"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Invalid talker identifier"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_nmea_parser() {
+        let mut p = NmeaParser::new();
+
         // String test
         p.push_string("a".into(), "b".into());
         assert_eq!(p.strings_count(), 1);
@@ -861,6 +2003,235 @@ mod test {
         assert_eq!(p.vsds_count(), 0);
     }
 
+    #[test]
+    fn test_known_vessels() {
+        let mut p = NmeaParser::new();
+        assert_eq!(p.known_vessels().count(), 0);
+
+        p.push_vsd(
+            230123450,
+            ais::VesselStaticData {
+                name: Some("ALPHA".into()),
+                ..Default::default()
+            },
+        );
+        p.push_vsd(
+            271041815,
+            ais::VesselStaticData {
+                name: Some("BRAVO".into()),
+                ..Default::default()
+            },
+        );
+
+        let mut vessels: Vec<(u32, Option<String>)> = p
+            .known_vessels()
+            .map(|(mmsi, vsd)| (*mmsi, vsd.name.clone()))
+            .collect();
+        vessels.sort();
+        assert_eq!(
+            vessels,
+            vec![
+                (230123450, Some("ALPHA".into())),
+                (271041815, Some("BRAVO".into()))
+            ]
+        );
+
+        p.reset();
+        assert_eq!(p.known_vessels().count(), 0);
+    }
+
+    #[test]
+    fn test_forget_vessel() {
+        let mut p = NmeaParser::new();
+
+        // Type 24 part A of two vessels
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,H3KMWfP00000000000000000000,2*38"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.vsds_count(), 2);
+
+        // First fragment of type 5 message
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.strings_count(), 1);
+
+        p.forget_vessel(230123450);
+        assert_eq!(p.vsds_count(), 1);
+        assert!(p.saved_vsds.contains_key(&271041815));
+        assert_eq!(p.strings_count(), 1);
+
+        p.forget_vessel(369190000);
+        assert_eq!(p.strings_count(), 0);
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,2,3,B,1@0000000000000,2*55"),
+            Ok(ParsedMessage::Incomplete)
+        );
+
+        // Part B of the remaining vessel completes its static data
+        match p.parse_sentence("!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => {
+                assert_eq!(vsd.mmsi, 271041815);
+                assert_eq!(vsd.name, Some("PROGUY".into()));
+            }
+            _ => panic!("Expected VesselStaticData message"),
+        }
+    }
+
+    #[test]
+    fn test_forget_vessel_out_of_order_fragments() {
+        let mut p = NmeaParser::new();
+
+        // The last fragment arrives before the first one and the middle one is missing
+        assert_eq!(
+            p.parse_sentence("!AIVDM,3,3,5,A,1@0000000000000,2*50"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,3,1,5,A,55P5TL01VIaAL@7WKO@mBplU@<PDhh,0*74"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.strings_count(), 2);
+
+        p.forget_vessel(369190000);
+        assert_eq!(p.strings_count(), 0);
+        assert!(p.saved_sentences.is_empty());
+    }
+
+    #[test]
+    fn test_drain_incomplete() {
+        let mut p = NmeaParser::new();
+        let first = "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E";
+        assert_eq!(p.parse_sentence(first), Ok(ParsedMessage::Incomplete));
+        assert_eq!(p.strings_count(), 1);
+
+        let fragments = p.drain_incomplete();
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].sentence_type, "!VDM");
+        assert_eq!(fragments[0].message_id, 3);
+        assert_eq!(fragments[0].channel, "B");
+        assert_eq!(fragments[0].fragment_number, 1);
+        assert_eq!(
+            fragments[0].payload,
+            "55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53"
+        );
+        assert_eq!(fragments[0].sentence, Some(first.into()));
+        assert_eq!(p.strings_count(), 0);
+        assert!(p.drain_incomplete().is_empty());
+
+        // The second fragment alone doesn't complete the message anymore
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,2,3,B,1@0000000000000,2*55"),
+            Ok(ParsedMessage::Incomplete)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_window() {
+        let mut p = NmeaParser::new();
+        let sentence = "!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A";
+        assert!(matches!(
+            p.parse_sentence(sentence),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert!(matches!(
+            p.parse_sentence(sentence),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+
+        p.set_duplicate_window(2);
+        assert!(matches!(
+            p.parse_sentence(sentence),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert_eq!(p.parse_sentence(sentence), Ok(ParsedMessage::Duplicate));
+
+        // A different message of the same vessel isn't a duplicate
+        match p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CR,0*49") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.mmsi, 371798000);
+            }
+            _ => panic!("Expected VesselDynamicData message"),
+        }
+
+        // The payload is forgotten when it falls out of the window
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C"),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D"),
+            Ok(ParsedMessage::Incomplete)
+        ));
+        assert!(matches!(
+            p.parse_sentence(sentence),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_window_multipart() {
+        let mut p = NmeaParser::new();
+        p.set_duplicate_window(10);
+
+        // Two different type 5 messages sharing the second fragment
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,2,2,3,B,1@0000000000000,2*55"),
+            Ok(ParsedMessage::VesselStaticData(_))
+        ));
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,1,4,B,55PUTL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*59"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,2,2,4,B,1@0000000000000,2*52"),
+            Ok(ParsedMessage::VesselStaticData(_))
+        ));
+        assert_eq!(p.strings_count(), 0);
+
+        // The same message repeated is a duplicate and leaves no fragments behind
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,2,3,B,1@0000000000000,2*55"),
+            Ok(ParsedMessage::Duplicate)
+        );
+        assert_eq!(p.strings_count(), 0);
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let mut p = NmeaParser::new();
+        match p.parse_bytes(b"!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A") {
+            Ok(msg) => match msg.message {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    assert_eq!(vdd.mmsi, 371798000);
+                }
+                _ => panic!("Expected VesselDynamicData message"),
+            },
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        assert_eq!(
+            p.parse_bytes(b"!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g2\xE908CQ,0*4A"),
+            Err(ParseError::InvalidSentence(
+                "Non-ASCII byte 0xE9 at position 38".into()
+            ))
+        );
+    }
+
     #[test]
     fn test_country() {
         assert_eq!(vsd(230992580).country().unwrap(), "FI");
@@ -875,10 +2246,260 @@ mod test {
         assert_eq!(vsd(412511368).country().unwrap(), "CN");
         assert_eq!(vsd(512003200).country().unwrap(), "NZ");
         assert_eq!(vsd(995126020).country(), None);
-        assert_eq!(vsd(2300049).country(), None);
+        assert_eq!(vsd(2300049).country().unwrap(), "FI");
+        assert_eq!(vsd(27612345).country().unwrap(), "EE");
         assert_eq!(vsd(0).country(), None);
     }
 
+    #[test]
+    fn test_extract_mid() {
+        // Ship
+        assert_eq!(ais::extract_mid(230992580), Some(230));
+        // Coast station
+        assert_eq!(ais::extract_mid(2300049), Some(230));
+        assert_eq!(ais::extract_mid(3669945), Some(366));
+        // Group
+        assert_eq!(ais::extract_mid(23012345), Some(230));
+        // Categories without MID in a fixed position
+        assert_eq!(ais::extract_mid(970123456), None);
+        assert_eq!(ais::extract_mid(1234567), None);
+        assert_eq!(ais::extract_mid(0), None);
+    }
+
+    #[test]
+    fn test_mmsi_category() {
+        use ais::MmsiCategory;
+        assert_eq!(vsd(230992580).mmsi_category(), MmsiCategory::Ship);
+        assert_eq!(vsd(2300049).mmsi_category(), MmsiCategory::CoastStation);
+        assert_eq!(vsd(111230123).mmsi_category(), MmsiCategory::SarAircraft);
+        assert_eq!(vsd(992301234).mmsi_category(), MmsiCategory::AidToNavigation);
+        assert_eq!(vsd(995126020).mmsi_category(), MmsiCategory::AidToNavigation);
+        assert_eq!(vsd(970123456).mmsi_category(), MmsiCategory::Sart);
+        assert_eq!(vsd(972123456).mmsi_category(), MmsiCategory::Mob);
+        assert_eq!(vsd(974123456).mmsi_category(), MmsiCategory::Epirb);
+        assert_eq!(vsd(982301234).mmsi_category(), MmsiCategory::AuxiliaryCraft);
+        assert_eq!(ais::mmsi_category(23012345), MmsiCategory::Group);
+        assert_eq!(ais::mmsi_category(12345678), MmsiCategory::Unknown);
+        assert_eq!(ais::mmsi_category(0), MmsiCategory::Unknown);
+        assert_eq!(ais::mmsi_category(1_000_000_000), MmsiCategory::Unknown);
+
+        // Coast station MIDs are limited to 200-799
+        assert_eq!(ais::mmsi_category(1_999_999), MmsiCategory::Unknown);
+        assert_eq!(ais::mmsi_category(2_000_000), MmsiCategory::CoastStation);
+        assert_eq!(ais::mmsi_category(7_999_999), MmsiCategory::CoastStation);
+        assert_eq!(ais::mmsi_category(8_000_000), MmsiCategory::Unknown);
+        assert_eq!(ais::mmsi_category(9_999_999), MmsiCategory::Unknown);
+    }
+
+    #[test]
+    fn test_truncated_ais_payload() {
+        assert_eq!(ais::min_bits_for_type(1), 168);
+        assert_eq!(ais::min_bits_for_type(5), 423);
+        assert_eq!(ais::min_bits_for_type(63), 0);
+
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,B,15N4cJ`005Jrek0H@9n`,0*1E"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Type 1 message too short: 120 bits (minimum 168 required)"
+            )))
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,B,B52K>;h00Fc>jpUlNV@ikwpUo,0*19"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Type 18 message too short: 150 bits (minimum 168 required)"
+            )))
+        );
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,B,15N4cJ`005Jrek0H@9n`DW5608EP,0*13")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_min_bits_boundaries() {
+        assert_eq!(ais::min_bits_for_type(7), 72);
+        assert_eq!(ais::min_bits_for_type(13), 72);
+        assert_eq!(ais::min_bits_for_type(21), 271);
+        assert_eq!(ais::min_bits_for_type(22), 145);
+
+        // The shortest payloads which are long enough and the longest ones which aren't
+        let mut p = NmeaParser::new();
+        // Type 7 decoding isn't implemented but the length check is passed
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,1,1,,A,73KMWfP00000,0*75"),
+            Err(ParseError::UnsupportedSentenceType(_))
+        ));
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,73KMWfP0000,0*45"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Type 7 message too short: 66 bits (minimum 72 required)"
+            )))
+        );
+        assert!(p.parse_sentence("!AIVDM,1,1,,A,=3KMWfP00000,0*7F").is_ok());
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,=3KMWfP0000,0*4F"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Type 13 message too short: 66 bits (minimum 72 required)"
+            )))
+        );
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,E3KMWfP000000000000000000000000000000000000000,0*07")
+            .is_ok());
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,E3KMWfP00000000000000000000000000000000000000,0*37"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Type 21 message too short: 270 bits (minimum 271 required)"
+            )))
+        );
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,F3KMWfP000000000000000000,0*34")
+            .is_ok());
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,F3KMWfP00000000000000000,0*04"),
+            Err(ParseError::InvalidSentence(String::from(
+                "Type 22 message too short: 144 bits (minimum 145 required)"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_type_filter() {
+        let mut p = NmeaParser::new();
+        p.set_type_filter(&[1, 2, 3, 18]);
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,1,1,,B,15N4cJ`005Jrek0H@9n`DW5608EP,0*13"),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,2,3,B,1@0000000000000,2*55"),
+            Ok(ParsedMessage::Filtered(5))
+        );
+
+        // GNSS sentences are not affected
+        assert!(matches!(
+            p.parse_sentence("$IIHDT,15.0,T*16"),
+            Ok(ParsedMessage::Hdt(_))
+        ));
+
+        p.clear_type_filter();
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D"),
+            Ok(ParsedMessage::Incomplete)
+        ));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let mut vdd = ais::VesselDynamicData {
+            latitude: Some(60.0),
+            longitude: Some(25.0),
+            sog_knots: Some(10.0),
+            cog: Some(0.0),
+            ..Default::default()
+        };
+
+        // Northbound: 10 knots for 6 minutes is one nautical mile
+        let (lat, lon) = vdd.extrapolate(360.0).unwrap();
+        assert::close(lat, 60.0 + 1.0 / 60.0, 0.000001);
+        assert::close(lon, 25.0, 0.000001);
+
+        // Eastbound: one minute of longitude is half a nautical mile at 60°N
+        vdd.cog = Some(90.0);
+        let (lat, lon) = vdd.extrapolate(180.0).unwrap();
+        assert::close(lat, 60.0, 0.000001);
+        assert::close(lon, 25.0 + 1.0 / 60.0, 0.0001);
+
+        vdd.sog_knots = None;
+        assert_eq!(vdd.extrapolate(60.0), None);
+    }
+
+    #[test]
+    fn test_navigation_status() {
+        use ais::NavigationStatus;
+        let descriptions = [
+            "Under way using engine",
+            "At anchor",
+            "Not under command",
+            "Restricted manoeuverability",
+            "Constrained by her draught",
+            "Moored",
+            "Aground",
+            "Engaged in fishing",
+            "Under way sailing",
+            "Reserved for high speed craft",
+            "Reserved for wing in ground craft",
+            "Power-driven vessel towing astern",
+            "Power-driven vessel pushing ahead or towing alongside",
+            "Reserved for future use",
+            "AIS-SART, MOB-AIS or EPIRB-AIS active",
+            "Not defined",
+        ];
+        for (code, description) in descriptions.iter().enumerate() {
+            let status = NavigationStatus::from_u8(code as u8);
+            assert_eq!(status.to_value(), code as u8);
+            assert_eq!(status.description(), *description);
+            assert_eq!(NavigationStatus::new(code as u8), status);
+        }
+        assert_eq!(NavigationStatus::from_u8(14), NavigationStatus::AisSartIsActive);
+        assert_eq!(NavigationStatus::from_u8(15), NavigationStatus::NotDefined);
+        assert_eq!(NavigationStatus::from_u8(16), NavigationStatus::Reserved(16));
+        assert_eq!(NavigationStatus::from_u8(16).to_value(), 16);
+        assert_eq!(NavigationStatus::new(16), NavigationStatus::NotDefined);
+    }
+
+    #[test]
+    fn test_mmsi_string() {
+        assert_eq!(vsd(2300049).mmsi_string(), "002300049");
+        assert_eq!(vsd(230992580).mmsi_string(), "230992580");
+        assert_eq!(ais::format_mmsi(0), "000000000");
+        assert_eq!(ais::format_mmsi(1_000_000_000), "1000000000");
+    }
+
+    #[test]
+    fn test_nav_system() {
+        let mut p = NmeaParser::new();
+        let gga = p
+            .parse_sentence("$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        assert_eq!(gga.nav_system(), Some(gnss::NavigationSystem::Gps));
+        let gga = p
+            .parse_sentence("$GNGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*68")
+            .unwrap();
+        assert_eq!(gga.nav_system(), Some(gnss::NavigationSystem::Combination));
+        let hdt = p.parse_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert_eq!(hdt.nav_system(), Some(gnss::NavigationSystem::Gps));
+        let dpt = p.parse_sentence("$GNDPT,17.5,0.3*79").unwrap();
+        assert_eq!(dpt.nav_system(), Some(gnss::NavigationSystem::Combination));
+        let mtw = p.parse_sentence("$INMTW,17.9,C*1B").unwrap();
+        assert_eq!(mtw.nav_system(), Some(gnss::NavigationSystem::Other));
+        let vdm = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        assert_eq!(vdm.nav_system(), None);
+    }
+
+    #[test]
+    fn test_talker() {
+        let mut p = NmeaParser::new();
+        let ii = p.parse_sentence_with_tags("$IIMWV,295.4,T,33.3,N,A*02").unwrap();
+        let wi = p.parse_sentence_with_tags("$WIMWV,295.4,T,33.3,N,A*1C").unwrap();
+        assert_eq!(ii.message, wi.message);
+        assert_eq!(ii.talker(), Some("II"));
+        assert_eq!(wi.talker(), Some("WI"));
+
+        let vdm = p
+            .parse_sentence_with_tags("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        assert_eq!(vdm.talker(), Some("AI"));
+        let msg = NmeaMessage::without_tag_block(ParsedMessage::Incomplete);
+        assert_eq!(msg.talker(), None);
+        assert!(msg.original_sentences().is_empty());
+    }
+
     /// Create a `VesselStaticData` with the given MMSI
     fn vsd(mmsi: u32) -> ais::VesselStaticData {
         let mut vsd = ais::VesselStaticData::default();
@@ -1079,6 +2700,252 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_parse_complete() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_complete("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E"),
+            Ok(None)
+        );
+        match p.parse_complete("!AIVDM,2,2,3,B,1@0000000000000,2*55") {
+            Ok(Some(ParsedMessage::VesselStaticData(_))) => {}
+            _ => panic!("Expected VesselStaticData message after completing multipart"),
+        }
+        match p.parse_complete("!AIVDM,1,1,,B,15N4cJ`005Jrek0H@9n`DW5608EP,0*13") {
+            Ok(Some(ParsedMessage::VesselDynamicData(_))) => {}
+            _ => panic!("Expected VesselDynamicData message"),
+        }
+    }
+
+    #[test]
+    fn test_validate_framing() {
+        assert_eq!(validate_framing("$IIHDT,15.0,T*16"), Ok(()));
+        assert_eq!(
+            validate_framing("!AIVDM,1,1,,B,15N4cJ`005Jrek0H@9n`DW5608EP,0*13"),
+            Ok(())
+        );
+
+        // The body isn't decoded
+        assert_eq!(validate_framing("$IIXYZ,foo,bar"), Ok(()));
+
+        assert!(matches!(
+            validate_framing("$IIHDT,15.0,T*00"),
+            Err(ParseError::CorruptedSentence(_))
+        ));
+        assert!(matches!(
+            validate_framing("$II-HDT,15.0,T"),
+            Err(ParseError::InvalidSentence(_))
+        ));
+        assert!(matches!(
+            validate_framing("IIHDT,15.0,T"),
+            Err(ParseError::InvalidSentence(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_sentence_strict() {
+        let mut p = NmeaParser::new();
+        let first = "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E";
+        assert_eq!(
+            p.parse_sentence_strict(first),
+            Err(ParseError::NeedMoreFragments { have: 1, need: 2 })
+        );
+        assert_eq!(
+            p.parse_sentence_strict(first).unwrap_err().to_string(),
+            "Incomplete multipart message: 1 of 2 fragments received"
+        );
+        assert!(matches!(
+            p.parse_sentence_strict("!AIVDM,2,2,3,B,1@0000000000000,2*55"),
+            Ok(ParsedMessage::VesselStaticData(_))
+        ));
+        assert!(matches!(
+            p.parse_sentence_strict("$IIHDT,15.0,T*16"),
+            Ok(ParsedMessage::Hdt(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_batch() {
+        let mut p = NmeaParser::new();
+        let data = "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E\r\n\
+                    !AIVDM,2,2,3,B,1@0000000000000,2*55\r\n\
+                    \r\n\
+                    $IIHDT,15.0,T*16\n\
+                    $IIHDT,15.0,T*00\r\n";
+        let results = p.parse_batch(data);
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0], Ok(ParsedMessage::Incomplete));
+        assert!(matches!(results[1], Ok(ParsedMessage::VesselStaticData(_))));
+        assert!(matches!(results[2], Ok(ParsedMessage::Hdt(_))));
+        assert!(results[3].is_err());
+        assert!(p.parse_batch("\r\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut p = NmeaParser::new();
+        let log = "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E\n\
+                   $IIHDT,15.0,T*16\n\
+                   $IIHDT,15.0,T*00\n\
+                   \n\
+                   !AIVDM,2,2,3,B,1@0000000000000,2*55\n";
+        let results: Vec<_> = p.replay(log.lines()).collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0], (1, Ok(None)));
+        assert!(matches!(results[1], (2, Ok(Some(ParsedMessage::Hdt(_))))));
+        assert!(matches!(results[2], (3, Err(ParseError::CorruptedSentence(_)))));
+        assert!(matches!(
+            results[3],
+            (5, Ok(Some(ParsedMessage::VesselStaticData(_))))
+        ));
+    }
+
+    #[test]
+    fn test_split_fields() {
+        assert_eq!(
+            NmeaParser::split_fields(
+                "\\s:r003669945*09\\$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n"
+            ),
+            Ok(vec![
+                "$GPGGA", "123519", "4807.038", "N", "01131.000", "E", "1", "08", "0.9", "545.4",
+                "M", "46.9", "M", "", ""
+            ])
+        );
+        assert_eq!(
+            NmeaParser::split_fields("$PGRMZ,246,f,3*1B").map(|fields| fields.len()),
+            Ok(4)
+        );
+        assert!(matches!(
+            NmeaParser::split_fields("$GPGGA,123519,4807.038,N*00"),
+            Err(ParseError::CorruptedSentence(_))
+        ));
+        assert!(NmeaParser::split_fields("GPGGA").is_err());
+    }
+
+    #[test]
+    fn test_parse_sentence_owned() {
+        let mut p = NmeaParser::new();
+        let sentence = "\\s:r003669945*09\\!AIVDM,1,1,,B,15N4cJ`005Jrek0H@9n`DW5608EP,0*13";
+        let owned = p.parse_sentence_owned(&format!("  {}\r\n", sentence)).unwrap();
+        assert_eq!(owned.raw, sentence);
+        assert!(matches!(owned.parsed.message, ParsedMessage::VesselDynamicData(_)));
+        assert_eq!(
+            owned.parsed.tag_block.and_then(|tb| tb.source),
+            Some("r003669945".to_string())
+        );
+    }
+
+    #[test]
+    fn test_original_sentences() {
+        let mut p = NmeaParser::new();
+        let s1 = "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E";
+        let s2 = "!AIVDM,2,2,3,B,1@0000000000000,2*55";
+        let s3 = "$IIHDT,15.0,T*16";
+
+        let msg = p.parse_sentence_with_tags(s1).unwrap();
+        assert_eq!(msg.message, ParsedMessage::Incomplete);
+        assert_eq!(msg.original_sentences(), &[s1.to_string()]);
+
+        let msg = p.parse_sentence_with_tags(s2).unwrap();
+        assert!(matches!(msg.message, ParsedMessage::VesselStaticData(_)));
+        assert_eq!(msg.original_sentences(), &[s1.to_string(), s2.to_string()]);
+
+        let msg = p.parse_sentence_with_tags(&format!("\\s:r003669945*09\\{}", s3)).unwrap();
+        assert_eq!(msg.original_sentences(), &[s3.to_string()]);
+    }
+
+    #[test]
+    fn test_keep_raw_bits() {
+        let sentence = "!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A";
+
+        let mut p = NmeaParser::new();
+        assert_eq!(p.parse_sentence_with_tags(sentence).unwrap().raw_bits(), None);
+
+        p.keep_raw_bits(true);
+        let msg = p.parse_sentence_with_tags(sentence).unwrap();
+        let raw_bits = msg.raw_bits().unwrap();
+        assert_eq!(raw_bits.bit_length, 168);
+        assert_eq!(raw_bits.data.len(), 21);
+        // Message type 1 and repeat indicator 0
+        assert_eq!(raw_bits.data[0] >> 2, 1);
+
+        let msg = p.parse_sentence_with_tags("$IIHDT,15.0,T*16").unwrap();
+        assert_eq!(msg.raw_bits(), None);
+    }
+
+    #[test]
+    fn test_reception_time() {
+        let mut p = NmeaParser::new();
+        let msg = p
+            .parse_sentence_with_tags(
+                r"\c:1614861000,s:sat42*51\!AIVDM,1,1,,A,K3KMWf`0rNTIu65`,0*0E",
+            )
+            .unwrap();
+        match &msg.message {
+            ParsedMessage::VesselDynamicData(vdd) => {
+                assert_eq!(vdd.mmsi, 230123450);
+                assert::close(vdd.latitude.unwrap_or(0.0), 60.15, 0.01);
+            }
+            _ => {
+                panic!("Expected VesselDynamicData");
+            }
+        }
+        assert_eq!(
+            msg.reception_time(),
+            Some(Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 0).unwrap())
+        );
+
+        // Type 27 without tag block time
+        let msg = p
+            .parse_sentence_with_tags("!AIVDM,1,1,,A,K3KMWf`0rNTIu65`,0*0E")
+            .unwrap();
+        assert_eq!(msg.reception_time(), None);
+
+        // Class A position report
+        let msg = p
+            .parse_sentence_with_tags(
+                r"\c:1614861000*54\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A",
+            )
+            .unwrap();
+        assert_eq!(msg.reception_time(), None);
+    }
+
+    #[test]
+    fn test_best_timestamp() {
+        let reference = Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 50).unwrap();
+        let mut p = NmeaParser::new();
+
+        // Tag block time has precedence
+        let msg = p
+            .parse_sentence_with_tags(
+                r"\c:1614861000*54\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A",
+            )
+            .unwrap();
+        assert_eq!(
+            msg.best_timestamp(reference),
+            Some(Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 0).unwrap())
+        );
+
+        // Report second 33 is 17 seconds before the reference
+        let msg = p
+            .parse_sentence_with_tags("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        assert_eq!(
+            msg.best_timestamp(reference),
+            Some(Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 33).unwrap())
+        );
+
+        // Snapping over minute boundary
+        let reference = Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 1).unwrap();
+        assert_eq!(
+            msg.best_timestamp(reference),
+            Some(Utc.with_ymd_and_hms(2021, 3, 4, 12, 29, 33).unwrap())
+        );
+
+        let msg = p.parse_sentence_with_tags("$IIHDT,15.0,T*16").unwrap();
+        assert_eq!(msg.best_timestamp(reference), None);
+    }
+
     #[test]
     fn test_multipart_out_of_order_fragments() {
         let mut parser = NmeaParser::new();
@@ -1113,3 +2980,10 @@ pub fn parse_sentence_with_tags(sentence: &str) -> Result<NmeaMessage, ParseErro
     let mut parser = NmeaParser::new();
     parser.parse_sentence_with_tags(sentence)
 }
+
+/// Check the framing of a single NMEA sentence without decoding its body: the start character,
+/// the checksum if present and the characters of the sentence formatter. The errors are the same
+/// as `NmeaParser::parse_sentence` returns for these checks. Tag blocks aren't supported.
+pub fn validate_framing(sentence: &str) -> Result<(), ParseError> {
+    frame_sentence(sentence).map(|_| ())
+}
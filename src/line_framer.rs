@@ -0,0 +1,185 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Allocation-free line framer for assembling sentences from a byte stream.
+
+/// Default capacity of `LineFramer` in bytes. It leaves room for a tag block in front of a
+/// sentence of the maximum length of 82 characters.
+pub const DEFAULT_LINE_CAPACITY: usize = 256;
+
+/// Framer which assembles lines from bytes received one at a time, for example from a UART
+/// interrupt handler. The bytes are stored in a fixed-capacity buffer of `N` bytes so no
+/// allocator is needed. A line which doesn't fit in the buffer is dropped as a whole and the
+/// drop is counted by `dropped_lines`.
+///
+/// ```
+/// let mut framer = nmea_parser::LineFramer::<64>::new();
+/// let mut lines = 0;
+/// for b in b"$IIHDT,15.0,T*16\r\n".iter() {
+///     if let Some(line) = framer.push_byte(*b) {
+///         assert_eq!(line, "$IIHDT,15.0,T*16");
+///         lines += 1;
+///     }
+/// }
+/// assert_eq!(lines, 1);
+/// ```
+pub struct LineFramer<const N: usize = DEFAULT_LINE_CAPACITY> {
+    buffer: [u8; N],
+    len: usize,
+    overflowed: bool,
+    dropped_lines: usize,
+}
+
+impl<const N: usize> LineFramer<N> {
+    /// Construct a new framer with an empty buffer.
+    pub fn new() -> LineFramer<N> {
+        LineFramer {
+            buffer: [0; N],
+            len: 0,
+            overflowed: false,
+            dropped_lines: 0,
+        }
+    }
+
+    /// Push a received byte to the framer. When the byte is `\n` the line collected so far is
+    /// returned without the line terminator. Empty lines, lines with invalid UTF-8 and lines
+    /// longer than the buffer capacity are not returned. The latter two are counted by
+    /// `dropped_lines`.
+    pub fn push_byte(&mut self, b: u8) -> Option<&str> {
+        if b == b'\n' {
+            let len = self.len;
+            let overflowed = self.overflowed;
+            self.len = 0;
+            self.overflowed = false;
+            if overflowed {
+                return None;
+            }
+            let line = &self.buffer[..len];
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                return None;
+            }
+            return match core::str::from_utf8(line) {
+                Ok(s) => Some(s),
+                Err(_) => {
+                    self.dropped_lines += 1;
+                    None
+                }
+            };
+        }
+
+        if self.overflowed {
+            // Skip the rest of the overlong line
+        } else if self.len < N {
+            self.buffer[self.len] = b;
+            self.len += 1;
+        } else {
+            warn!("Line longer than {} bytes dropped", N);
+            self.len = 0;
+            self.overflowed = true;
+            self.dropped_lines += 1;
+        }
+        None
+    }
+
+    /// Number of lines dropped because of buffer overflow or invalid UTF-8.
+    pub fn dropped_lines(&self) -> usize {
+        self.dropped_lines
+    }
+
+    /// Return true if the framer is currently skipping the rest of an overlong line.
+    pub fn is_overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Discard the bytes of the unfinished line.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+    }
+}
+
+impl<const N: usize> Default for LineFramer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NmeaParser, ParsedMessage};
+
+    /// Push the bytes to the framer and collect the returned lines.
+    fn push_all<const N: usize>(framer: &mut LineFramer<N>, bytes: &[u8]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for b in bytes {
+            if let Some(line) = framer.push_byte(*b) {
+                lines.push(line.to_string());
+            }
+        }
+        lines
+    }
+
+    #[test]
+    fn test_line_framer() {
+        let mut framer = LineFramer::<32>::new();
+        assert_eq!(
+            push_all(&mut framer, b"$IIHDT,15.0,T*16\n$IIHDT,1"),
+            vec!["$IIHDT,15.0,T*16"]
+        );
+        assert_eq!(
+            push_all(&mut framer, b"5.0,T*16\n\n"),
+            vec!["$IIHDT,15.0,T*16"]
+        );
+        assert_eq!(framer.dropped_lines(), 0);
+
+        let mut p = NmeaParser::new();
+        let line = push_all(&mut framer, b"$IIHDT,15.0,T*16\n").pop().unwrap();
+        assert!(matches!(p.parse_sentence(&line), Ok(ParsedMessage::Hdt(_))));
+    }
+
+    #[test]
+    fn test_line_framer_crlf() {
+        let mut framer: LineFramer = LineFramer::new();
+        assert_eq!(
+            push_all(&mut framer, b"$IIHDT,15.0,T*16\r\n\r\n$IIHDT,15.0,T*16\r\n"),
+            vec!["$IIHDT,15.0,T*16", "$IIHDT,15.0,T*16"]
+        );
+    }
+
+    #[test]
+    fn test_line_framer_overflow() {
+        let mut framer = LineFramer::<16>::new();
+        assert_eq!(
+            push_all(&mut framer, b"$IIHDT,15.0,T*16\r\n$IIHDT,1.0,T*23\r\n"),
+            vec!["$IIHDT,1.0,T*23"]
+        );
+        assert_eq!(framer.dropped_lines(), 1);
+
+        assert_eq!(
+            push_all(&mut framer, b"!AIVDM,1,1,,A,13u?"),
+            Vec::<String>::new()
+        );
+        assert!(framer.is_overflowed());
+        framer.reset();
+        assert!(!framer.is_overflowed());
+        assert_eq!(push_all(&mut framer, b"ABC\n"), vec!["ABC"]);
+        assert_eq!(framer.dropped_lines(), 2);
+    }
+}
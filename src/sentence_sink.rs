@@ -0,0 +1,143 @@
+/*
+Copyright 2021 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! `std::io::Write` adapter which parses sentences as bytes are written to it.
+
+use super::*;
+use std::io;
+
+/// Sink which buffers the bytes written to it, splits them to lines and parses each complete
+/// line with an internal `NmeaParser`. Every complete message and every parse error is passed
+/// to the callback. Incomplete multipart messages are not reported. Bytes of an unfinished line
+/// are retained until the rest of the line is written.
+///
+/// ```
+/// use std::io::Write;
+/// let mut count = 0;
+/// let mut sink = nmea_parser::SentenceSink::new(|_msg| count += 1);
+/// sink.write_all(b"$IIHDT,15.0,T*16\r\n").unwrap();
+/// drop(sink);
+/// assert_eq!(count, 1);
+/// ```
+pub struct SentenceSink<F>
+where
+    F: FnMut(Result<NmeaMessage, ParseError>),
+{
+    parser: NmeaParser,
+    buffer: Vec<u8>,
+    callback: F,
+}
+
+impl<F> SentenceSink<F>
+where
+    F: FnMut(Result<NmeaMessage, ParseError>),
+{
+    /// Construct a new sink with an empty parser.
+    pub fn new(callback: F) -> SentenceSink<F> {
+        SentenceSink::with_parser(NmeaParser::new(), callback)
+    }
+
+    /// Construct a new sink which uses the given parser.
+    pub fn with_parser(parser: NmeaParser, callback: F) -> SentenceSink<F> {
+        SentenceSink {
+            parser,
+            buffer: Vec::new(),
+            callback,
+        }
+    }
+
+    /// Return a reference to the internal parser.
+    pub fn parser(&self) -> &NmeaParser {
+        &self.parser
+    }
+
+    /// Consume the sink and return the internal parser. Bytes of an unfinished line are lost.
+    pub fn into_parser(self) -> NmeaParser {
+        self.parser
+    }
+
+    /// Parse a line and pass the result to the callback.
+    fn process_line(&mut self, line: &[u8]) {
+        let line = match core::str::from_utf8(line) {
+            Ok(s) => s.trim(),
+            Err(e) => {
+                (self.callback)(Err(ParseError::InvalidSentence(format!(
+                    "Invalid UTF-8 in sentence: {}",
+                    e
+                ))));
+                return;
+            }
+        };
+        if line.is_empty() {
+            return;
+        }
+        match self.parser.parse_sentence_with_tags(line) {
+            Ok(msg) if msg.message == ParsedMessage::Incomplete => {}
+            result => (self.callback)(result),
+        }
+    }
+}
+
+impl<F> io::Write for SentenceSink<F>
+where
+    F: FnMut(Result<NmeaMessage, ParseError>),
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        while let Some(pos) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            self.process_line(&line);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_sentence_sink() {
+        let mut results = Vec::new();
+        {
+            let mut sink = SentenceSink::new(|result| results.push(result));
+            sink.write_all(b"$IIHDT,15.0,T*16\r\n!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PD")
+                .unwrap();
+            sink.write_all(b"hh000000001S;AJ::4A80?4i@E53,0*3E\r\n!AIVDM,2,2,3,B,1@0000")
+                .unwrap();
+            sink.write_all(b"000000000,2*55\r\n$IIHDT,15.0,T*FF\r\n\r\n$IIHDT,1")
+                .unwrap();
+        }
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(
+            results[0].as_ref().map(|msg| &msg.message),
+            Ok(ParsedMessage::Hdt(_))
+        ));
+        assert!(matches!(
+            results[1].as_ref().map(|msg| &msg.message),
+            Ok(ParsedMessage::VesselStaticData(_))
+        ));
+        assert!(matches!(results[2], Err(ParseError::CorruptedSentence(_))));
+    }
+}
@@ -27,7 +27,8 @@ use alloc::vec::Vec;
 use crate::error::ParseError;
 
 /// Represents sentence grouping information from NMEA 4.10 tag blocks
-#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SentenceGrouping {
     /// Sentence number in the group
     pub sentence_number: u32,
@@ -38,7 +39,8 @@ pub struct SentenceGrouping {
 }
 
 /// Represents a parsed NMEA tag block
-#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagBlock {
     /// UNIX timestamp in seconds or milliseconds (c field)
     pub timestamp: Option<u64>,
@@ -52,14 +54,18 @@ pub struct TagBlock {
     /// Line count (n field)
     pub line_count: Option<u32>,
     
-    /// Relative time (r field)
-    pub relative_time: Option<u32>,
+    /// Relative time (r field). Some implementations emit negative offsets.
+    pub relative_time: Option<i64>,
     
     /// Source/station identifier (s field)
     pub source: Option<String>,
     
-    /// Text string (t/i field) - at most 15 characters
+    /// Text string (t field) - at most 15 characters
     pub text: Option<String>,
+
+    /// Sentence originator/identifier (i field) - at most 15 characters. Many feeds use
+    /// `i` and `t` interchangeably, so check both if only one of them is expected.
+    pub originator: Option<String>,
 }
 
 impl TagBlock {
@@ -73,6 +79,7 @@ impl TagBlock {
             relative_time: None,
             source: None,
             text: None,
+            originator: None,
         }
     }
     
@@ -85,6 +92,18 @@ impl TagBlock {
     /// * `Ok(TagBlock)` - Successfully parsed tag block
     /// * `Err(ParseError)` - Error parsing the tag block
     pub fn parse(tag_block_str: &str) -> Result<TagBlock, ParseError> {
+        Self::parse_with_mode(tag_block_str, false)
+    }
+
+    /// Parse a tag block from a string like `parse` but return `ParseError::InvalidSentence`
+    /// when the value of a length-limited field (`d`, `t` or `i`) is longer than 15 characters.
+    /// `parse` ignores such values.
+    pub fn parse_strict(tag_block_str: &str) -> Result<TagBlock, ParseError> {
+        Self::parse_with_mode(tag_block_str, true)
+    }
+
+    /// Parse a tag block in lenient or strict mode.
+    fn parse_with_mode(tag_block_str: &str, strict: bool) -> Result<TagBlock, ParseError> {
         // Check that the string starts and ends with backslashes
         if !tag_block_str.starts_with('\\') || !tag_block_str.ends_with('\\') {
             return Err(ParseError::InvalidSentence(
@@ -144,9 +163,7 @@ impl TagBlock {
                         tag_block.timestamp = field_value.parse::<u64>().ok();
                     },
                     "d" => {
-                        if field_value.len() <= 15 {
-                            tag_block.destination = Some(field_value.to_string());
-                        }
+                        tag_block.destination = Self::parse_limited(field_type, field_value, strict)?;
                     },
                     "g" => {
                         tag_block.grouping = Self::parse_grouping(field_value)?;
@@ -155,15 +172,16 @@ impl TagBlock {
                         tag_block.line_count = field_value.parse::<u32>().ok();
                     },
                     "r" => {
-                        tag_block.relative_time = field_value.parse::<u32>().ok();
+                        tag_block.relative_time = field_value.parse::<i64>().ok();
                     },
                     "s" => {
                         tag_block.source = Some(field_value.to_string());
                     },
-                    "t" | "i" => {
-                        if field_value.len() <= 15 {
-                            tag_block.text = Some(field_value.to_string());
-                        }
+                    "t" => {
+                        tag_block.text = Self::parse_limited(field_type, field_value, strict)?;
+                    },
+                    "i" => {
+                        tag_block.originator = Self::parse_limited(field_type, field_value, strict)?;
                     },
                     _ => {
                         // Ignore unknown field types for forward compatibility
@@ -175,6 +193,26 @@ impl TagBlock {
         Ok(tag_block)
     }
     
+    /// Parse the value of a field which is limited to 15 characters. Longer values are errors
+    /// in strict mode and ignored otherwise.
+    fn parse_limited(
+        field_type: &str,
+        value: &str,
+        strict: bool,
+    ) -> Result<Option<String>, ParseError> {
+        if value.len() <= 15 {
+            Ok(Some(value.to_string()))
+        } else if strict {
+            Err(ParseError::InvalidSentence(format!(
+                "Tag block {} field longer than 15 characters: {}",
+                field_type, value
+            )))
+        } else {
+            warn!("Tag block {} field longer than 15 characters ignored: {}", field_type, value);
+            Ok(None)
+        }
+    }
+
     /// Parse sentence grouping from a string like "1-2-73874"
     fn parse_grouping(value: &str) -> Result<Option<SentenceGrouping>, ParseError> {
         let parts: Vec<&str> = value.split('-').collect();
@@ -267,6 +305,29 @@ mod tests {
         assert_eq!(tag_block.text, Some("hello".to_string()));
     }
     
+    #[test]
+    fn test_parse_tag_block_with_text_and_originator() {
+        let tag_block_str = r"\s:station1,i:AB12,t:hello*7D\";
+        let result = TagBlock::parse(tag_block_str);
+        
+        assert!(result.is_ok());
+        let tag_block = result.unwrap();
+        
+        assert_eq!(tag_block.text, Some("hello".to_string()));
+        assert_eq!(tag_block.originator, Some("AB12".to_string()));
+    }
+    
+    #[test]
+    fn test_parse_negative_relative_time() {
+        let tag_block_str = r"\r:-120*56\";
+        let result = TagBlock::parse(tag_block_str);
+        
+        assert!(result.is_ok());
+        let tag_block = result.unwrap();
+        
+        assert_eq!(tag_block.relative_time, Some(-120));
+    }
+    
     #[test]
     fn test_parse_invalid_checksum() {
         let tag_block_str = r"\c:1241544035*FF\";
@@ -319,4 +380,24 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_strict_field_length() {
+        let tag_block_str = r"\d:ABCDEFGHIJKLMNOPQRST,c:1614861000*32\";
+
+        // Lenient mode ignores the overlong value
+        let tag_block = TagBlock::parse(tag_block_str).unwrap();
+        assert_eq!(tag_block.destination, None);
+        assert_eq!(tag_block.timestamp, Some(1614861000));
+
+        assert_eq!(
+            TagBlock::parse_strict(tag_block_str),
+            Err(ParseError::InvalidSentence(
+                "Tag block d field longer than 15 characters: ABCDEFGHIJKLMNOPQRST".to_string()
+            ))
+        );
+
+        let tag_block = TagBlock::parse_strict(r"\d:ABCDEFGHIJKLMNO*1E\").unwrap();
+        assert_eq!(tag_block.destination, Some("ABCDEFGHIJKLMNO".to_string()));
+    }
+}
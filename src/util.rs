@@ -16,6 +16,7 @@ limitations under the License.
 use super::*;
 
 use chrono::Duration;
+use num_traits::Float;
 
 const AIS_CHAR_BITS: usize = 6;
 
@@ -33,10 +34,25 @@ pub(crate) fn make_fragment_key(
     )
 }
 
+/// Calculate 64-bit FNV-1a hash of the given AIS payload armored string.
+pub(crate) fn payload_hash(payload: &str) -> u64 {
+    payload.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 /// Convert AIS VDM/VDO payload armored string into a `BitVec`.
-pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, String> {
+pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, ParseError> {
     let mut bv = BitVec::<usize, LocalBits>::with_capacity(payload.len() * 6);
-    for c in payload.chars() {
+    for (pos, c) in payload.chars().enumerate() {
+        // Valid armoring characters are '0'..='W' and '`'..='w'
+        if !matches!(c, '0'..='W' | '`'..='w') {
+            return Err(ParseError::CorruptedSentence(format!(
+                "Illegal character {:?} in AIS payload at position {}",
+                c, pos
+            )));
+        }
+
         let mut ci = (c as u8) - 48;
         if ci > 40 {
             ci -= 8;
@@ -51,6 +67,18 @@ pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, String> {
     Ok(bv)
 }
 
+/// Pack bits into bytes in big-endian bit order. The last byte is padded with zero bits.
+pub(crate) fn pack_bits(bits: &BitSlice) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (i, b)| if *b { acc | (1u8 << (7 - i)) } else { acc })
+        })
+        .collect()
+}
+
 /// Pick a numberic field from `BitVec`.
 pub(crate) fn pick_u64(bv: &BitVec, index: usize, len: usize) -> u64 {
     let mut res = 0;
@@ -104,6 +132,32 @@ pub(crate) fn pick_string(bv: &BitVec, index: usize, char_count: usize) -> Strin
     res
 }
 
+/// Pick the application ID of a binary message (types 6, 8, 25 and 26) starting at the given
+/// bit offset and return it as `(dac, fid)` together with the index of the first data bit. The
+/// application ID follows the 40-bit common header of broadcast messages. In addressed messages
+/// it follows the destination MMSI, which is followed by retransmit flag and spare bit in type 6
+/// (offset 72) but not in types 25 and 26 (offset 70). The application ID is present only in
+/// structured messages. `None` is returned if the message is too short.
+pub(crate) fn pick_app_id(
+    bv: &BitVec,
+    offset: usize,
+    structured: bool,
+) -> (Option<(u16, u8)>, usize) {
+    if !structured {
+        (None, offset)
+    } else if bv.len() < offset + 16 {
+        (None, offset + 16)
+    } else {
+        (
+            Some((
+                pick_u64(bv, offset, 10) as u16,
+                pick_u64(bv, offset + 10, 6) as u8,
+            )),
+            offset + 16,
+        )
+    }
+}
+
 /// Pick ETA based on UTC month, day, hour and minute.
 pub(crate) fn pick_eta(bv: &BitVec, index: usize) -> Result<Option<DateTime<Utc>>, ParseError> {
     pick_eta_with_now(
@@ -191,6 +245,35 @@ pub(crate) fn pick_number_field<T: core::str::FromStr>(
         .transpose()
 }
 
+/// Parse value and unit indicator pairs of the given field range, calling `assign` with each
+/// unit and value. Fields after the range (e.g. vendor specific ones) are ignored.
+pub(crate) fn parse_unit_fields<F: FnMut(&str, Option<f64>)>(
+    split: &[&str],
+    fields: core::ops::Range<usize>,
+    mut assign: F,
+) -> Result<(), String> {
+    for i in (fields.start..core::cmp::min(split.len(), fields.end)).step_by(2) {
+        assign(split.get(i + 1).unwrap_or(&""), pick_number_field(split, i)?);
+    }
+    Ok(())
+}
+
+/// Parse status flag field (`A` = true, `V` = false) or return `None` in case of an empty field.
+/// The given name is used in the error message of other values.
+pub(crate) fn parse_status_flag(
+    split: &[&str],
+    num: usize,
+    name: &str,
+) -> Result<Option<bool>, String> {
+    let s = split.get(num).unwrap_or(&"");
+    match *s {
+        "A" => Ok(Some(true)),
+        "V" => Ok(Some(false)),
+        "" => Ok(None),
+        _ => Err(format!("Invalid {}: {}", name, s)),
+    }
+}
+
 /// Pick hex-formatted field from a comma-separated sentence or `None` in case of an empty field.
 pub(crate) fn pick_hex_field<T: num_traits::Num>(
     split: &[&str],
@@ -216,22 +299,38 @@ pub(crate) fn pick_string_field(split: &[&str], num: usize) -> Option<String> {
     }
 }
 
-/// Parse time field of format HHMMSS and convert it to `DateTime<Utc>` using the current time.
+/// Parse time field of format HHMMSS.SS and convert it to `DateTime<Utc>` using the current time.
 pub(crate) fn parse_hhmmss(hhmmss: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, ParseError> {
-    let (hour, minute, second) =
-        parse_time(hhmmss).map_err(|_| format!("Invalid time format: {}", hhmmss))?;
-    parse_valid_utc(now.year(), now.month(), now.day(), hour, minute, second, 0)
+    let (hour, minute, second, nano) = parse_time_with_fractions(hhmmss)
+        .map_err(|_| format!("Invalid time format: {}", hhmmss))?;
+    parse_valid_utc(now.year(), now.month(), now.day(), hour, minute, second, nano)
 }
 
-/// Parse time fields of formats YYMMDD and HHMMSS and convert them to `DateTime<Utc>`.
+/// Parse duration field of format HHMMSS.SS and convert it to seconds. Empty field results
+/// `None`.
+pub(crate) fn parse_hhmmss_duration(hhmmss: &str) -> Result<Option<f64>, ParseError> {
+    if hhmmss.is_empty() {
+        return Ok(None);
+    }
+    let (hour, minute, second, nano) = parse_time_with_fractions(hhmmss)
+        .map_err(|_| format!("Invalid duration format: {}", hhmmss))?;
+    if minute > 59 || second > 59 {
+        return Err(format!("Invalid duration format: {}", hhmmss).into());
+    }
+    Ok(Some(
+        (hour * 3600 + minute * 60 + second) as f64 + nano as f64 / 1_000_000_000.0,
+    ))
+}
+
+/// Parse time fields of formats YYMMDD and HHMMSS.SS and convert them to `DateTime<Utc>`.
 pub(crate) fn parse_yymmdd_hhmmss(yymmdd: &str, hhmmss: &str) -> Result<DateTime<Utc>, ParseError> {
     let now = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
     let century = (now.year() / 100) * 100;
     let (day, month, year) =
         parse_date(yymmdd).map_err(|_| format!("Invalid date format: {}", yymmdd))?;
-    let (hour, minute, second) =
-        parse_time(hhmmss).map_err(|_| format!("Invalid time format: {}", hhmmss))?;
-    parse_valid_utc(century + year, month, day, hour, minute, second, 0)
+    let (hour, minute, second, nano) = parse_time_with_fractions(hhmmss)
+        .map_err(|_| format!("Invalid time format: {}", hhmmss))?;
+    parse_valid_utc(century + year, month, day, hour, minute, second, nano)
 }
 
 /// Parse time field of format HHMMSS.SS and convert it to `DateTime<Utc>` using the given date.
@@ -296,14 +395,6 @@ fn parse_date(yymmdd: &str) -> Result<(u32, u32, i32), ParseError> {
     Ok((day, month, year))
 }
 
-/// Parse hour, minute and second from HHMMSS string.
-fn parse_time(hhmmss: &str) -> Result<(u32, u32, u32), ParseError> {
-    let hour = pick_s2(hhmmss, 0).parse::<u32>()?;
-    let minute = pick_s2(hhmmss, 2).parse::<u32>()?;
-    let second = pick_s2(hhmmss, 4).parse::<u32>()?;
-    Ok((hour, minute, second))
-}
-
 /// Parse hour, minute, second and nano seconds from HHMMSS.SS string.
 fn parse_time_with_fractions(hhmmss: &str) -> Result<(u32, u32, u32, u32), ParseError> {
     let hour = pick_s2(hhmmss, 0).parse::<u32>()?;
@@ -367,17 +458,33 @@ fn pick_s2(s: &str, i: usize) -> &str {
 /// Argument `lat_string` expects format DDMM.MMM representing latitude.
 /// Argument `hemisphere` expects "N" for north or "S" for south. If `hemisphere` value
 /// is something else, north is quietly used as a fallback.
+/// If `permissive` is true, a value which can't be a latitude, such as a longitude in DDDMM.MMM
+/// format or a magnitude beyond 90 degrees, results `None` instead of an error or an impossible
+/// value.
 pub(crate) fn parse_latitude_ddmm_mmm(
     lat_string: &str,
     hemisphere: &str,
+    permissive: bool,
 ) -> Result<Option<f64>, ParseError> {
     // DDMM.MMM
     if lat_string.is_empty() {
         return Ok(None);
     }
 
-    // Validate: 4 digits, a decimal point, then 1 or more digits
+    // Longitude in latitude's position: 5 digits, then a decimal point
     let byte_string = lat_string.as_bytes();
+    if permissive
+        && byte_string.iter().take(5).all(|c| c.is_ascii_digit())
+        && byte_string.get(5) == Some(&b'.')
+    {
+        warn!(
+            "Ignored latitude which looks like a longitude: {}",
+            lat_string
+        );
+        return Ok(None);
+    }
+
+    // Validate: 4 digits, a decimal point, then 1 or more digits
     if !(byte_string.iter().take(4).all(|c| c.is_ascii_digit())
         && byte_string.get(4) == Some(&b'.')
         && byte_string
@@ -397,6 +504,10 @@ pub(crate) fn parse_latitude_ddmm_mmm(
     let d = lat_string[0..2].parse::<f64>().unwrap_or(0.0);
     let m = lat_string[2..end].parse::<f64>().unwrap_or(0.0);
     let val = d + m / 60.0;
+    if permissive && val > 90.0 {
+        warn!("Ignored latitude beyond 90 degrees: {}", lat_string);
+        return Ok(None);
+    }
     Ok(Some(match hemisphere {
         "N" => val,
         "S" => -val,
@@ -448,6 +559,56 @@ pub(crate) fn parse_longitude_dddmm_mmm(
     }))
 }
 
+/// Format the given latitude in degrees as DDMM.MMMM and hemisphere fields.
+pub(crate) fn format_latitude_ddmm_mmmm(latitude: f64) -> String {
+    let (degrees, minutes) = degrees_and_minutes(latitude);
+    format!(
+        "{:02}{:07.4},{}",
+        degrees,
+        minutes,
+        if latitude < 0.0 { "S" } else { "N" }
+    )
+}
+
+/// Format the given longitude in degrees as DDDMM.MMMM and hemisphere fields.
+pub(crate) fn format_longitude_dddmm_mmmm(longitude: f64) -> String {
+    let (degrees, minutes) = degrees_and_minutes(longitude);
+    format!(
+        "{:03}{:07.4},{}",
+        degrees,
+        minutes,
+        if longitude < 0.0 { "W" } else { "E" }
+    )
+}
+
+/// Split the magnitude of the given angle to whole degrees and minutes rounded to four decimals.
+fn degrees_and_minutes(angle: f64) -> (u32, f64) {
+    let ten_thousandths = Float::round(Float::abs(angle) * 600_000.0) as u64;
+    (
+        (ten_thousandths / 600_000) as u32,
+        (ten_thousandths % 600_000) as f64 / 10_000.0,
+    )
+}
+
+/// Format the time of day as HHMMSS.SS, the inverse of `parse_hhmmss`.
+pub(crate) fn format_nmea_time(timestamp: &DateTime<Utc>) -> String {
+    // Leap seconds are represented as nanoseconds over one second
+    let hundredths = core::cmp::min(timestamp.nanosecond() / 10_000_000, 99);
+    format!("{}.{:02}", timestamp.format("%H%M%S"), hundredths)
+}
+
+/// Format the date as DDMMYY, the inverse of the date part of `parse_yymmdd_hhmmss`.
+pub(crate) fn format_nmea_date(timestamp: &DateTime<Utc>) -> String {
+    timestamp.format("%d%m%y").to_string()
+}
+
+/// Complete the given sentence by appending the checksum, e.g. `$IIHDT,15.0,T` becomes
+/// `$IIHDT,15.0,T*16`.
+pub(crate) fn append_checksum(sentence: &str) -> String {
+    let checksum = sentence.bytes().skip(1).fold(0u8, |acc, b| acc ^ b);
+    format!("{}*{:02X}", sentence, checksum)
+}
+
 /// Parse latitude from two string.
 /// Argument `lat_string` expects a latitude offset in minutes
 /// Argument `hemisphere` expects "N" for north or "S" for south. If `hemisphere` value
@@ -492,6 +653,28 @@ pub(crate) fn parse_longitude_m_m(
     }
 }
 
+/// Return the given AIS latitude unless its magnitude exceeds 90 degrees, which means the value
+/// is corrupt even though it isn't the "not available" sentinel.
+pub(crate) fn valid_latitude(latitude: f64) -> Option<f64> {
+    if Float::abs(latitude) <= 90.0 {
+        Some(latitude)
+    } else {
+        debug!("Discarding out of range latitude: {}", latitude);
+        None
+    }
+}
+
+/// Return the given AIS longitude unless its magnitude exceeds 180 degrees, which means the value
+/// is corrupt even though it isn't the "not available" sentinel.
+pub(crate) fn valid_longitude(longitude: f64) -> Option<f64> {
+    if Float::abs(longitude) <= 180.0 {
+        Some(longitude)
+    } else {
+        debug!("Discarding out of range longitude: {}", longitude);
+        None
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -515,11 +698,31 @@ mod test {
                 );
             }
             Err(e) => {
-                assert_eq!(e, "OK");
+                assert_eq!(e.to_string(), "OK");
             }
         }
     }
 
+    #[test]
+    fn test_parse_payload_illegal_character() {
+        assert_eq!(
+            parse_payload("w7b 0P1"),
+            Err(ParseError::CorruptedSentence(
+                "Illegal character ' ' in AIS payload at position 3".into()
+            ))
+        );
+        assert!(parse_payload("w7bX").is_err());
+        assert!(parse_payload("w7bx").is_err());
+    }
+
+    #[test]
+    fn test_pack_bits() {
+        let bv = bitvec![1, 0, 1, 1, 0, 1, 0, 0, 1, 1];
+        assert_eq!(pack_bits(&bv), vec![0xb4, 0xc0]);
+        assert_eq!(pack_bits(&bv[8..]), vec![0xc0]);
+        assert!(pack_bits(&bv[0..0]).is_empty());
+    }
+
     #[test]
     fn test_pick_u64() {
         let bv = bitvec![1, 0, 1, 1, 0, 1];
@@ -556,6 +759,61 @@ mod test {
         assert_eq!(pick_string(&bv, 0, bv.len() / 6), "?AG_4:!");
     }
 
+    #[test]
+    fn test_pick_app_id() {
+        let mut bv = BitVec::new();
+        bv.resize(88, false);
+        bv.set(49, true); // DAC 1 of a broadcast message
+        bv.set(55, true); // FID 1 of a broadcast message
+        bv.set(81, true); // DAC 1 of an addressed message
+        bv.set(86, true); // FID 2 of an addressed message
+        assert_eq!(pick_app_id(&bv, 40, true), (Some((1, 1)), 56));
+        assert_eq!(pick_app_id(&bv, 72, true), (Some((1, 2)), 88));
+        assert_eq!(pick_app_id(&bv, 70, true), (Some((0, 16)), 86));
+        assert_eq!(pick_app_id(&bv, 40, false), (None, 40));
+        assert_eq!(pick_app_id(&bv, 72, false), (None, 72));
+        bv.truncate(80);
+        assert_eq!(pick_app_id(&bv, 72, true), (None, 88));
+    }
+
+    #[test]
+    fn test_format_nmea_time_and_date_round_trip() {
+        let new_year = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        for timestamp in [
+            new_year - Duration::milliseconds(10),
+            new_year,
+            new_year + Duration::milliseconds(1230),
+            Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap(),
+        ] {
+            let date = format_nmea_date(&timestamp);
+            let time = format_nmea_time(&timestamp);
+            assert_eq!(parse_yymmdd_hhmmss(&date, &time).ok(), Some(timestamp));
+        }
+        assert_eq!(format_nmea_date(&(new_year - Duration::seconds(1))), "311220");
+        assert_eq!(format_nmea_time(&(new_year - Duration::milliseconds(10))), "235959.99");
+        assert_eq!(format_nmea_date(&new_year), "010121");
+        assert_eq!(
+            format_nmea_date(&Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap()),
+            "010100"
+        );
+    }
+
+    #[test]
+    fn test_format_fields() {
+        assert_eq!(format_latitude_ddmm_mmmm(49.274166667), "4916.4500,N");
+        assert_eq!(format_latitude_ddmm_mmmm(-0.5), "0030.0000,S");
+        assert_eq!(format_longitude_dddmm_mmmm(-123.185333333), "12311.1200,W");
+        assert_eq!(format_longitude_dddmm_mmmm(24.99999999), "02500.0000,E");
+        let timestamp = Utc.with_ymd_and_hms(2020, 11, 19, 2, 4, 6).unwrap();
+        assert_eq!(format_nmea_time(&timestamp), "020406.00");
+        assert_eq!(
+            format_nmea_time(&(timestamp + Duration::milliseconds(250))),
+            "020406.25"
+        );
+        assert_eq!(format_nmea_date(&timestamp), "191120");
+        assert_eq!(append_checksum("$IIHDT,15.0,T"), "$IIHDT,15.0,T*16");
+    }
+
     #[test]
     fn test_pick_eta() {
         // Valid case
@@ -706,6 +964,14 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_valid_coordinates() {
+        assert_eq!(valid_latitude(-90.0), Some(-90.0));
+        assert_eq!(valid_latitude(90.5), None);
+        assert_eq!(valid_longitude(180.0), Some(180.0));
+        assert_eq!(valid_longitude(-180.1), None);
+    }
+
     #[test]
     fn test_parse_latitude_m_m() {
         assert::close(